@@ -2,11 +2,21 @@
 
 use crate::clap_app::{build_app, *};
 
+use fmodifier::terminal::arguments::Arguments;
+use fmodifier::terminal::runner::OutputFormat;
+
 use clap::ArgMatches;
 
 pub enum AppAction {
     RunInteractive,
-    RunFromFile { file_name: String },
+    RunFromFile {
+        file_name: String,
+        arguments: Arguments,
+        strict: bool,
+    },
+    EditFile {
+        file_name: String,
+    },
 }
 
 /// The `App` structure is responsible for parsing CLI arguments.
@@ -29,13 +39,42 @@ impl App {
                     .expect("Couldn't find the file name argument")
                     .clone();
 
-                AppAction::RunFromFile { file_name }
+                let positional: Vec<String> = sub_matches
+                    .get_many::<String>(SCRIPT_ARGS_ARG)
+                    .unwrap_or_default()
+                    .cloned()
+                    .collect();
+                let named = sub_matches
+                    .get_many::<(String, String)>(NAMED_ARG)
+                    .unwrap_or_default()
+                    .cloned()
+                    .collect();
+
+                AppAction::RunFromFile {
+                    file_name,
+                    arguments: Arguments::new(positional, named),
+                    strict: !sub_matches.get_flag(NO_STRICT_ARG),
+                }
             }
+            Some((EDIT_SUBCMD, sub_matches)) => AppAction::EditFile {
+                file_name: sub_matches
+                    .get_one::<String>(FILE_NAME_ARG)
+                    .expect("Couldn't find the file name argument")
+                    .clone(),
+            },
             None => AppAction::RunInteractive,
             _ => unreachable!(),
         }
     }
 
+    /// The `output_format` function returns how results of commands are written.
+    pub fn output_format(&self) -> OutputFormat {
+        self.matches
+            .get_one::<String>(OUTPUT_ARG)
+            .and_then(|name| OutputFormat::from_name(name))
+            .unwrap_or(OutputFormat::Text)
+    }
+
     fn new_with_matches(matches: ArgMatches) -> App {
         App { matches }
     }
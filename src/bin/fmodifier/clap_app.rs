@@ -1,7 +1,30 @@
-use clap::{crate_authors, crate_name, crate_version, Arg, Command};
+use fmodifier::terminal::arguments::Arguments;
+
+use clap::{crate_authors, crate_name, crate_version, Arg, ArgAction, Command};
 
 pub const FILE_NAME_ARG: &str = "file_name";
+pub const SCRIPT_ARGS_ARG: &str = "script_args";
+pub const NAMED_ARG: &str = "arg";
+pub const STRICT_ARG: &str = "strict";
+pub const NO_STRICT_ARG: &str = "no-strict";
+pub const OUTPUT_ARG: &str = "output";
 pub const EXEC_FILE_SUBCMD: &str = "execfile";
+pub const EDIT_SUBCMD: &str = "edit";
+
+/// The `parse_named_argument` function parses `key=value` pair of `--arg` option.
+fn parse_named_argument(pair: &str) -> Result<(String, String), String> {
+    let Some((key, value)) = pair.split_once('=') else {
+        return Err(format!("'{pair}' isn't of form key=value"));
+    };
+
+    if !Arguments::is_name(key) {
+        return Err(format!(
+            "'{key}' isn't a valid name: use latin letters, digits and underscores"
+        ));
+    }
+
+    Ok((key.to_string(), value.to_string()))
+}
 
 /// The `build_app` function returns specially built `clap::Command` object.
 pub fn build_app() -> Command {
@@ -9,11 +32,58 @@ pub fn build_app() -> Command {
         .version(crate_version!())
         .author(crate_authors!())
         .subcommand_required(false)
+        .arg(
+            Arg::new(OUTPUT_ARG)
+                .long(OUTPUT_ARG)
+                .global(true)
+                .value_name("FORMAT")
+                .value_parser(["text", "json"])
+                .default_value("text")
+                .help("Write results of commands as text or as one JSON object per command"),
+        )
+        .subcommand(
+            Command::new(EXEC_FILE_SUBCMD)
+                .arg(
+                    Arg::new(FILE_NAME_ARG)
+                        .required(false)
+                        .help("For executing a file"),
+                )
+                .arg(
+                    Arg::new(SCRIPT_ARGS_ARG)
+                        .num_args(0..)
+                        .allow_negative_numbers(true)
+                        .help("Positional arguments available in the script as $1, $2, ..."),
+                )
+                .arg(
+                    Arg::new(NAMED_ARG)
+                        .long(NAMED_ARG)
+                        .value_name("KEY=VALUE")
+                        .action(ArgAction::Append)
+                        .value_parser(parse_named_argument)
+                        .help("Named argument available in the script as $KEY"),
+                )
+                .arg(
+                    Arg::new(STRICT_ARG)
+                        .long(STRICT_ARG)
+                        .action(ArgAction::SetTrue)
+                        .overrides_with(NO_STRICT_ARG)
+                        .help("Stop at the first error with a non-zero exit code (default)"),
+                )
+                .arg(
+                    Arg::new(NO_STRICT_ARG)
+                        .long(NO_STRICT_ARG)
+                        .action(ArgAction::SetTrue)
+                        .overrides_with(STRICT_ARG)
+                        .help("Report errors and keep executing the script"),
+                ),
+        )
         .subcommand(
-            Command::new(EXEC_FILE_SUBCMD).arg(
-                Arg::new(FILE_NAME_ARG)
-                    .required(false)
-                    .help("For executing a file"),
-            ),
+            Command::new(EDIT_SUBCMD)
+                .about("Opens the full-screen hex editor over a file")
+                .arg(
+                    Arg::new(FILE_NAME_ARG)
+                        .required(true)
+                        .help("The file to edit"),
+                ),
         )
 }
@@ -1,30 +1,125 @@
 use crate::app::{App, AppAction};
 
+use fmodifier::terminal::arguments::Arguments;
 use fmodifier::terminal::commander::{FileReader, Terminal};
-use fmodifier::terminal::runner::Runner;
+use fmodifier::terminal::editor::edit;
+use fmodifier::terminal::runner::{OutputFormat, Runner};
 
-const FILE_UNEXISTS_ERROR: &str = "A file with the name isn't found.";
+use serde_json::json;
 
-fn run_interactive() {
+use std::fs::{self, OpenOptions};
+use std::io::{Result as IoResult, Write};
+use std::process;
+
+const READ_SCRIPT_ERROR: &str = "Couldn't read the script";
+const READ_FILE_ERROR: &str = "Couldn't read the file";
+const WRITE_FILE_ERROR: &str = "Couldn't write the file";
+const EDITOR_ERROR: &str = "The editor has failed";
+
+/// The `IO_ERROR_CLASS` is the class of errors of reading and writing files in JSON output.
+const IO_ERROR_CLASS: &str = "io";
+
+/// The `report` function writes the message about the file given to the program. In JSON, it's
+/// written as an object like ones of the runner with the `text` field.
+fn report(format: OutputFormat, file_path: &str, message: String) {
+    match format {
+        OutputFormat::Text => println!("- {}", message),
+        OutputFormat::Json => println!(
+            "{}",
+            json!({
+                "status": "ok",
+                "source": file_path,
+                "line": 0,
+                "included_from": [],
+                "text": message,
+            })
+        ),
+    }
+}
+
+/// The `fail` function writes the error of the file given to the program and exits with code 1.
+fn fail(format: OutputFormat, file_path: &str, message: &str, e: std::io::Error) -> ! {
+    match format {
+        OutputFormat::Text => eprintln!("Error: {} {}: {}", message, file_path, e),
+        OutputFormat::Json => println!(
+            "{}",
+            json!({
+                "status": "error",
+                "source": file_path,
+                "line": 0,
+                "included_from": [],
+                "error": { "class": IO_ERROR_CLASS, "message": format!("{}: {}", message, e) },
+            })
+        ),
+    }
+    process::exit(1);
+}
+
+fn run_interactive(format: OutputFormat) {
     let terminal = Terminal::new();
-    let runner = Runner::new(terminal);
+    let mut runner = Runner::new(terminal);
+    runner.set_output_format(format);
 
     runner.for_each(|_| {});
 }
 
-fn run_from_file(file_path: &str) {
-    let file_reader = FileReader::new(file_path).expect(FILE_UNEXISTS_ERROR);
-    let runner = Runner::new(file_reader);
+/// The `run_from_file` function executes the file. In strict mode, the process exits with the code
+/// of the error class if a command fails.
+fn run_from_file(file_path: &str, arguments: Arguments, strict: bool, format: OutputFormat) {
+    let file_reader = match FileReader::new(file_path) {
+        Ok(file_reader) => file_reader,
+        Err(e) => fail(format, file_path, READ_SCRIPT_ERROR, e),
+    };
+    let mut runner = Runner::with_arguments(file_reader, arguments);
+    runner.set_strict(strict);
+    runner.set_output_format(format);
 
-    runner.for_each(|_| {});
+    runner.by_ref().for_each(|_| {});
+
+    if let Some(class) = runner.failure() {
+        process::exit(class.exit_code());
+    }
+}
+
+/// The `write_in_place` function writes the bytes over the file without truncating it. The editor
+/// doesn't change the size of the file, so every byte is overwritten and a failed write keeps the
+/// rest of the file intact.
+fn write_in_place(file_path: &str, bytes: &[u8]) -> IoResult<()> {
+    let mut file = OpenOptions::new().write(true).open(file_path)?;
+    file.write_all(bytes)?;
+    file.sync_all()
+}
+
+/// The `edit_file` function opens the hex editor over the file. If the editor is closed with
+/// saving and there're changes, they're written to the file.
+fn edit_file(file_path: &str, format: OutputFormat) {
+    let fail = |message: &str, e: std::io::Error| -> ! { fail(format, file_path, message, e) };
+
+    let original = fs::read(file_path).unwrap_or_else(|e| fail(READ_FILE_ERROR, e));
+    let edited = edit(file_path, original.clone()).unwrap_or_else(|e| fail(EDITOR_ERROR, e));
+
+    let message = match edited {
+        Some(edited) if edited != original => {
+            write_in_place(file_path, &edited).unwrap_or_else(|e| fail(WRITE_FILE_ERROR, e));
+            format!("File {} is saved.", file_path)
+        }
+        Some(_) => format!("File {} isn't changed.", file_path),
+        None => format!("Changes of file {} are discarded.", file_path),
+    };
+    report(format, file_path, message);
 }
 
 /// The `run` function takes an application (`App`) and launch the program.
 pub fn run(app: &App) {
     match app.action() {
         AppAction::RunInteractive => {
-            run_interactive();
+            run_interactive(app.output_format());
         }
-        AppAction::RunFromFile { file_name } => run_from_file(&file_name),
+        AppAction::RunFromFile {
+            file_name,
+            arguments,
+            strict,
+        } => run_from_file(&file_name, arguments, strict, app.output_format()),
+        AppAction::EditFile { file_name } => edit_file(&file_name, app.output_format()),
     }
 }
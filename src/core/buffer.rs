@@ -1,14 +1,17 @@
-/// The `Buffer` structure is just a sequence of bytes in given size.
+use super::integer::{decode_int, encode_int, Endianness, IntType};
+
+/// The `Buffer` structure is just a sequence of bytes in given size. The size can be changed by
+/// `resize` and `splice_bytes`; other functions keep it.
 #[derive(Clone)]
 pub struct Buffer {
-    data: Box<[u8]>,
+    data: Vec<u8>,
 }
 
 impl Buffer {
     /// The `new` function creates a buffer in given size. All bytes in new buffer is 0.
     pub fn new(size: usize) -> Self {
         Buffer {
-            data: Box::from_iter((0..size).map(|_| 0_u8)),
+            data: vec![0; size],
         }
     }
 
@@ -35,20 +38,56 @@ impl Buffer {
     /// doesn't raise any error; just returning count will be less that length of given bytes.
     pub fn write_bytes(&mut self, bytes: &[u8], start_with: usize) -> usize {
         bytes
-            .into_iter()
+            .iter()
             .zip(self.data.iter_mut().skip(start_with))
             .map(|(&byte, buf_cell)| *buf_cell = byte)
             .count()
     }
 
+    /// The `swap_bytes` function swaps bytes in first argument with bytes of buffer starting with
+    /// index in second argument. The function returns count of swapped bytes.
+    ///
+    /// If an end was reached early than all bytes in first argument were swapped, the function
+    /// doesn't raise any error; just returning count will be less that length of given bytes.
+    pub fn swap_bytes(&mut self, bytes: &mut [u8], start_with: usize) -> usize {
+        bytes
+            .iter_mut()
+            .zip(self.data.iter_mut().skip(start_with))
+            .map(|(byte, buf_cell)| std::mem::swap(byte, buf_cell))
+            .count()
+    }
+
+    /// The `write_int` function writes the value as integer of given type in given order of bytes
+    /// starting with given index.
+    ///
+    /// If the value doesn't fit the type or the integer goes beyond the boundaries, the function
+    /// writes nothing and returns `None`.
+    pub fn write_int(
+        &mut self,
+        value: i128,
+        int_type: IntType,
+        endianness: Endianness,
+        index: usize,
+    ) -> Option<()> {
+        let bytes = encode_int(value, int_type, endianness)?;
+
+        self.data
+            .get_mut(index..index.checked_add(bytes.len())?)?
+            .copy_from_slice(&bytes);
+
+        Some(())
+    }
+
     /// The `fill_bytes` function fill bytes starting from `start` and ending at `end` indexes. If
     /// `start` go beyond the boundaries, the function returns `None`. The function returns count
     /// of written bytes.
     pub fn fill_bytes(&mut self, fill_by: u8, start: usize, end: usize) -> Option<usize> {
+        let end = end.min(self.len());
+
         Some(
             self.data
-                .get_mut(start..end.min(self.len()))?
-                .into_iter()
+                .get_mut(start..end)?
+                .iter_mut()
                 .map(|t| *t = fill_by)
                 .count(),
         )
@@ -56,14 +95,50 @@ impl Buffer {
 
     // }
 
+    // Resize Methods {
+
+    /// The `resize` function changes size of the buffer. New bytes are 0; bytes beyond the new
+    /// size are deleted.
+    pub fn resize(&mut self, new_size: usize) {
+        self.data.resize(new_size, 0);
+    }
+
+    /// The `splice_bytes` function replaces bytes starting from `start` and ending at `end` by
+    /// given bytes, so the size of the buffer changes by the difference of their counts. The
+    /// function returns the replaced bytes.
+    ///
+    /// Bytes are inserted if `start` equals `end` and deleted if given bytes are empty. If `start`
+    /// is greater than `end` or `end` goes beyond the boundaries, the function changes nothing and
+    /// returns `None`.
+    pub fn splice_bytes(&mut self, start: usize, end: usize, bytes: &[u8]) -> Option<Vec<u8>> {
+        if start > end || end > self.len() {
+            return None;
+        }
+
+        Some(self.data.splice(start..end, bytes.iter().copied()).collect())
+    }
+
+    // }
+
     // Read Methods {
 
     /// The `read_byte` returns a byte from a given index. If index is wrong anyway, the function
     /// causes panic.
+    #[allow(dead_code)]
     pub fn read_byte(&self, index: usize) -> u8 {
         self.data[index]
     }
 
+    /// The `read_int` function returns an integer of given type in given order of bytes starting
+    /// from given index. If the integer goes beyond the boundaries, the function returns `None`.
+    pub fn read_int(&self, int_type: IntType, endianness: Endianness, index: usize) -> Option<i128> {
+        let bytes = self
+            .data
+            .get(index..index.checked_add(int_type.width())?)?;
+
+        Some(decode_int(bytes, int_type, endianness))
+    }
+
     /// The `read_bytes` returns a slice of buffer's bytes starting from the first argument and
     /// ending by the second argument. If `start` goes beyond the boundaries, the function returns
     /// `None`.
@@ -73,3 +148,69 @@ impl Buffer {
 
     // }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffer(bytes: &[u8]) -> Buffer {
+        let mut buffer = Buffer::new(bytes.len());
+        buffer.write_bytes(bytes, 0);
+        buffer
+    }
+
+    fn bytes(buffer: &Buffer) -> &[u8] {
+        buffer.read_bytes(0, buffer.len()).unwrap()
+    }
+
+    #[test]
+    fn splice_inserts_bytes() {
+        let mut b = buffer(&[1, 2, 3]);
+
+        assert_eq!(b.splice_bytes(1, 1, &[7, 8]), Some(vec![]));
+        assert_eq!(bytes(&b), [1, 7, 8, 2, 3]);
+
+        assert_eq!(b.splice_bytes(5, 5, &[9]), Some(vec![]));
+        assert_eq!(bytes(&b), [1, 7, 8, 2, 3, 9]);
+    }
+
+    #[test]
+    fn splice_deletes_bytes() {
+        let mut b = buffer(&[1, 2, 3, 4]);
+
+        assert_eq!(b.splice_bytes(1, 3, &[]), Some(vec![2, 3]));
+        assert_eq!(bytes(&b), [1, 4]);
+
+        assert_eq!(b.splice_bytes(0, 2, &[]), Some(vec![1, 4]));
+        assert_eq!(b.len(), 0);
+    }
+
+    #[test]
+    fn splice_replaces_bytes() {
+        let mut b = buffer(&[1, 2, 3, 4]);
+
+        assert_eq!(b.splice_bytes(1, 2, &[7, 8, 9]), Some(vec![2]));
+        assert_eq!(bytes(&b), [1, 7, 8, 9, 3, 4]);
+    }
+
+    #[test]
+    fn splice_rejects_wrong_ranges() {
+        let mut b = buffer(&[1, 2, 3]);
+
+        assert_eq!(b.splice_bytes(2, 1, &[7]), None);
+        assert_eq!(b.splice_bytes(2, 4, &[]), None);
+        assert_eq!(b.splice_bytes(4, 4, &[7]), None);
+        assert_eq!(bytes(&b), [1, 2, 3]);
+    }
+
+    #[test]
+    fn resize_fills_by_zeros_and_truncates() {
+        let mut b = buffer(&[1, 2]);
+
+        b.resize(4);
+        assert_eq!(bytes(&b), [1, 2, 0, 0]);
+
+        b.resize(1);
+        assert_eq!(bytes(&b), [1]);
+    }
+}
@@ -0,0 +1,219 @@
+mod adler;
+mod crc;
+mod md5;
+mod sha256;
+
+use super::integer::IntType;
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The `Algorithm` enumeration contains all algorithms of checksums and hashes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    /// CRC-32 (IEEE 802.3), the same as in zip and png.
+    Crc32,
+    /// CRC-16/CCITT-FALSE: polynomial 0x1021, initial value 0xFFFF, no reflection.
+    Crc16,
+    Adler32,
+    /// Sum of bytes modulo 2^8.
+    Sum8,
+    /// Sum of bytes modulo 2^16.
+    Sum16,
+    /// Sum of bytes modulo 2^32.
+    Sum32,
+    /// XOR of all bytes.
+    Xor8,
+    Sha256,
+    Md5,
+}
+
+impl Algorithm {
+    /// The `from_name` function returns the algorithm with given name. If there's no algorithm
+    /// with the name, the function returns `None`.
+    pub fn from_name(name: &str) -> Option<Algorithm> {
+        match name {
+            "crc32" => Some(Algorithm::Crc32),
+            "crc16" => Some(Algorithm::Crc16),
+            "adler32" => Some(Algorithm::Adler32),
+            "sum8" => Some(Algorithm::Sum8),
+            "sum16" => Some(Algorithm::Sum16),
+            "sum32" => Some(Algorithm::Sum32),
+            "xor8" => Some(Algorithm::Xor8),
+            "sha256" => Some(Algorithm::Sha256),
+            "md5" => Some(Algorithm::Md5),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Algorithm::Crc32 => "crc32",
+            Algorithm::Crc16 => "crc16",
+            Algorithm::Adler32 => "adler32",
+            Algorithm::Sum8 => "sum8",
+            Algorithm::Sum16 => "sum16",
+            Algorithm::Sum32 => "sum32",
+            Algorithm::Xor8 => "xor8",
+            Algorithm::Sha256 => "sha256",
+            Algorithm::Md5 => "md5",
+        }
+    }
+
+    /// The `int_type` function returns the type of integer the checksum fits. For hashes, the
+    /// function returns `None`: they're sequences of bytes rather than integers.
+    pub fn int_type(&self) -> Option<IntType> {
+        match self {
+            Algorithm::Crc32 | Algorithm::Adler32 | Algorithm::Sum32 => Some(IntType::U32),
+            Algorithm::Crc16 | Algorithm::Sum16 => Some(IntType::U16),
+            Algorithm::Sum8 | Algorithm::Xor8 => Some(IntType::U8),
+            Algorithm::Sha256 | Algorithm::Md5 => None,
+        }
+    }
+
+    /// The `width` function returns count of bytes the checksum takes.
+    pub fn width(&self) -> usize {
+        match self {
+            Algorithm::Sha256 => 32,
+            Algorithm::Md5 => 16,
+            _ => self.int_type().unwrap().width(),
+        }
+    }
+
+    /// The `compute` function computes the checksum of given bytes.
+    pub fn compute(&self, bytes: &[u8]) -> Checksum {
+        match self {
+            Algorithm::Crc32 => Checksum::Int(crc::crc32(bytes) as u64),
+            Algorithm::Crc16 => Checksum::Int(crc::crc16_ccitt(bytes) as u64),
+            Algorithm::Adler32 => Checksum::Int(adler::adler32(bytes) as u64),
+            Algorithm::Sum8 => Checksum::Int(sum(bytes) % (1 << 8)),
+            Algorithm::Sum16 => Checksum::Int(sum(bytes) % (1 << 16)),
+            Algorithm::Sum32 => Checksum::Int(sum(bytes) % (1 << 32)),
+            Algorithm::Xor8 => Checksum::Int(bytes.iter().fold(0, |acc, byte| acc ^ byte) as u64),
+            Algorithm::Sha256 => Checksum::Digest(sha256::sha256(bytes).to_vec()),
+            Algorithm::Md5 => Checksum::Digest(md5::md5(bytes).to_vec()),
+        }
+    }
+}
+
+impl Display for Algorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+fn sum(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(0_u64, |acc, &byte| acc.wrapping_add(byte as u64))
+}
+
+/// The `Checksum` enumeration is the result of computing a checksum: an integer for checksums and
+/// a sequence of bytes for hashes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Checksum {
+    Int(u64),
+    Digest(Vec<u8>),
+}
+
+impl Display for Checksum {
+    /// Integers are written in hexadecimal and decimal forms, digests are written as hexadecimal
+    /// string.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Checksum::Int(value) => write!(f, "0x{value:x} ({value})"),
+            Checksum::Digest(bytes) => bytes.iter().try_for_each(|byte| write!(f, "{byte:02x}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHECK: &[u8] = b"123456789";
+    /// 56 bytes: the padding of MD5 and SHA-256 takes the second block.
+    const TWO_BLOCKS: &[u8] = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+    /// 80 bytes: the data itself takes more than one block.
+    const LONG: &[u8] =
+        b"12345678901234567890123456789012345678901234567890123456789012345678901234567890";
+
+    fn int(algorithm: Algorithm, bytes: &[u8]) -> u64 {
+        match algorithm.compute(bytes) {
+            Checksum::Int(value) => value,
+            Checksum::Digest(_) => panic!("{algorithm} isn't an integer checksum"),
+        }
+    }
+
+    fn digest(algorithm: Algorithm, bytes: &[u8]) -> String {
+        algorithm.compute(bytes).to_string()
+    }
+
+    #[test]
+    fn crc32() {
+        assert_eq!(int(Algorithm::Crc32, b""), 0);
+        assert_eq!(int(Algorithm::Crc32, b"abc"), 0x352441C2);
+        assert_eq!(int(Algorithm::Crc32, CHECK), 0xCBF43926);
+        assert_eq!(int(Algorithm::Crc32, LONG), 0x7CA94A72);
+    }
+
+    #[test]
+    fn crc16() {
+        assert_eq!(int(Algorithm::Crc16, b""), 0xFFFF);
+        assert_eq!(int(Algorithm::Crc16, b"abc"), 0x514A);
+        assert_eq!(int(Algorithm::Crc16, CHECK), 0x29B1);
+    }
+
+    #[test]
+    fn adler32() {
+        assert_eq!(int(Algorithm::Adler32, b""), 1);
+        assert_eq!(int(Algorithm::Adler32, b"abc"), 0x024D0127);
+        assert_eq!(int(Algorithm::Adler32, CHECK), 0x091E01DE);
+        assert_eq!(int(Algorithm::Adler32, LONG), 0x97B61069);
+        assert_eq!(int(Algorithm::Adler32, &[b'a'; 1_000_000]), 0x15D870F9);
+    }
+
+    #[test]
+    fn sums() {
+        assert_eq!(int(Algorithm::Sum8, &[0xFF, 0x02]), 0x01);
+        assert_eq!(int(Algorithm::Sum16, &[0xFF, 0x02]), 0x101);
+        assert_eq!(int(Algorithm::Sum32, CHECK), 477);
+        assert_eq!(int(Algorithm::Xor8, &[0x0F, 0xF0, 0x01]), 0xFE);
+    }
+
+    #[test]
+    fn md5() {
+        let md5 = |bytes| digest(Algorithm::Md5, bytes);
+
+        assert_eq!(md5(b""), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(md5(b"abc"), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(md5(CHECK), "25f9e794323b453885f5181f1b624d0b");
+        assert_eq!(md5(TWO_BLOCKS), "8215ef0796a20bcaaae116d3876c664a");
+        assert_eq!(md5(LONG), "57edf4a22be3c955ac49da2e2107b67a");
+    }
+
+    #[test]
+    fn sha256() {
+        let sha256 = |bytes| digest(Algorithm::Sha256, bytes);
+
+        assert_eq!(
+            sha256(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            sha256(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            sha256(CHECK),
+            "15e2b0d3c33891ebb0f1ef609ec419420c20e320ce94c65fbc8c3312448eb225"
+        );
+        assert_eq!(
+            sha256(TWO_BLOCKS),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+        assert_eq!(
+            sha256(&[b'a'; 1_000_000]),
+            "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"
+        );
+    }
+}
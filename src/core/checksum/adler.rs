@@ -0,0 +1,12 @@
+const ADLER_MODULO: u32 = 65521;
+
+/// The `adler32` function computes Adler-32 of given bytes.
+pub fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1_u32, 0_u32), |(a, b), &byte| {
+        let a = (a + byte as u32) % ADLER_MODULO;
+        let b = (b + a) % ADLER_MODULO;
+        (a, b)
+    });
+
+    (b << 16) | a
+}
@@ -0,0 +1,33 @@
+const CRC32_POLYNOMIAL: u32 = 0xEDB8_8320;
+
+const CRC16_CCITT_POLYNOMIAL: u16 = 0x1021;
+const CRC16_CCITT_INIT: u16 = 0xFFFF;
+
+/// The `crc32` function computes CRC-32 (IEEE 802.3) of given bytes. It's the reflected algorithm
+/// with initial value and final XOR 0xFFFFFFFF.
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let crc = bytes.iter().fold(u32::MAX, |crc, &byte| {
+        (0..8).fold(crc ^ byte as u32, |crc, _| {
+            if crc & 1 != 0 {
+                (crc >> 1) ^ CRC32_POLYNOMIAL
+            } else {
+                crc >> 1
+            }
+        })
+    });
+
+    !crc
+}
+
+/// The `crc16_ccitt` function computes CRC-16/CCITT-FALSE of given bytes.
+pub fn crc16_ccitt(bytes: &[u8]) -> u16 {
+    bytes.iter().fold(CRC16_CCITT_INIT, |crc, &byte| {
+        (0..8).fold(crc ^ ((byte as u16) << 8), |crc, _| {
+            if crc & 0x8000 != 0 {
+                (crc << 1) ^ CRC16_CCITT_POLYNOMIAL
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
@@ -1,3 +1,11 @@
+use super::checksum::Algorithm;
+use super::encoding::Encoding;
+use super::integer::{Endianness, IntType};
+use super::settings::Setting;
+use super::view::View;
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
 /// The `CoreCommand` enumeration contains all variants of commands.
 pub enum CoreCommand<'a> {
     // Command structure convention:
@@ -23,18 +31,27 @@ pub enum CoreCommand<'a> {
         buffer_name: &'a str,
         start: usize,
         end: usize,
+        view: View,
     },
     BufferInfo {
         buffer_name: &'a str,
     },
+    CopyBuffer {
+        buffer_name: &'a str,
+        new_buffer_name: String,
+    },
     BufferSetByte {
         buffer_name: &'a str,
         index: usize,
         value: u8,
     },
+    /// If `overwrite` is set, an existing file is truncated. The file is named `file_name` that
+    /// is either its path or the alias given by `as`.
     CreateFile {
+        file_path: String,
         file_name: String,
         file_size: usize,
+        overwrite: bool,
     },
     FromFileToBuffer {
         file_name: &'a str,
@@ -51,18 +68,66 @@ pub enum CoreCommand<'a> {
         file_start: usize,
     },
     OpenFile {
+        file_path: String,
+        file_name: String,
+    },
+    /// If `backup` is set, the file is copied to `<file_path>.bak` before opening.
+    OpenFileRw {
+        file_path: String,
         file_name: String,
+        backup: bool,
     },
     ShowFile {
         file_name: &'a str,
         start: usize,
         end: usize,
+        view: View,
     },
     BufferWriteBytes {
         buffer_name: &'a str,
         start: usize,
         bytes: Vec<u8>,
     },
+    BufferWriteInt {
+        buffer_name: &'a str,
+        index: usize,
+        int_type: IntType,
+        endianness: Endianness,
+        value: i128,
+    },
+    /// If `nul` is set, the text is followed by the terminating nul.
+    BufferWriteStr {
+        buffer_name: &'a str,
+        index: usize,
+        text: &'a str,
+        encoding: Encoding,
+        nul: bool,
+    },
+    /// New bytes are 0.
+    BufferResize {
+        buffer_name: &'a str,
+        new_size: usize,
+    },
+    BufferInsert {
+        buffer_name: &'a str,
+        offset: usize,
+        bytes: Vec<u8>,
+    },
+    BufferDelete {
+        buffer_name: &'a str,
+        start: usize,
+        end: usize,
+    },
+    BufferAppend {
+        buffer_name: &'a str,
+        bytes: Vec<u8>,
+    },
+    BufferReadInt {
+        buffer_name: &'a str,
+        index: usize,
+        int_type: IntType,
+        endianness: Endianness,
+    },
     MergeBuffers {
         left_buffer_name: &'a str,
         right_buffer_name: &'a str,
@@ -74,13 +139,361 @@ pub enum CoreCommand<'a> {
         start: usize,
         end: usize,
     },
+    /// If `overwrite` is set, an existing file is truncated.
     TurnBufferToFile {
         buffer_name: &'a str,
         new_file_name: String,
+        overwrite: bool,
+    },
+    SaveBuffer {
+        buffer_name: &'a str,
+        file_name: &'a str,
     },
     TurnFileToBuffer {
         file_name: &'a str,
         new_buffer_name: String,
     },
+    Diff {
+        left_name: &'a str,
+        right_name: &'a str,
+    },
+    Checksum {
+        algorithm: Algorithm,
+        name: &'a str,
+        start: usize,
+        end: usize,
+    },
+    /// If `layout` is `None`, the checksum is written in its natural width in little-endian order.
+    ChecksumWrite {
+        algorithm: Algorithm,
+        name: &'a str,
+        start: usize,
+        end: usize,
+        buffer_name: &'a str,
+        index: usize,
+        layout: Option<(IntType, Endianness)>,
+    },
+    /// `None` in the pattern matches any byte.
+    FindBytes {
+        name: &'a str,
+        pattern: Vec<Option<u8>>,
+    },
+    /// `None` in the pattern matches any byte.
+    FindAll {
+        pattern: Vec<Option<u8>>,
+    },
+    Let {
+        name: String,
+        value: i128,
+    },
+    /// The assertion fails if `value` is 0.
+    Assert {
+        value: i128,
+        message: Option<&'a str>,
+    },
+    List,
+    Drop {
+        name: &'a str,
+    },
+    Rename {
+        old_name: &'a str,
+        new_name: &'a str,
+    },
+    Undo,
+    Redo,
+    History,
+    HistorySetDepth {
+        depth: usize,
+    },
+    Set {
+        setting: Setting,
+    },
     Nop,
 }
+
+impl<'a> Display for CoreCommand<'a> {
+    /// The command is written the same way it's entered.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            CoreCommand::MakeBuffer {
+                buffer_name,
+                buffer_size,
+            } => write!(f, "make_buffer {buffer_name} {buffer_size}"),
+            CoreCommand::FillBuffer {
+                buffer_name,
+                value,
+                start,
+                end,
+            } => write!(f, "fill_buffer {buffer_name} {value} {start} {end}"),
+            CoreCommand::ShowBuffer {
+                buffer_name,
+                start,
+                end,
+                view,
+            } => {
+                write!(f, "show_buffer {buffer_name} {start} {end}")?;
+                write_view(f, *view)
+            }
+            CoreCommand::BufferInfo { buffer_name } => write!(f, "buffer_info {buffer_name}"),
+            CoreCommand::CopyBuffer {
+                buffer_name,
+                new_buffer_name,
+            } => write!(f, "copy_buffer {buffer_name} {new_buffer_name}"),
+            CoreCommand::BufferSetByte {
+                buffer_name,
+                index,
+                value,
+            } => write!(f, "buffer_set_byte {buffer_name} {index} {value}"),
+            CoreCommand::CreateFile {
+                file_path,
+                file_name,
+                file_size,
+                overwrite,
+            } => {
+                write!(f, "create_file ")?;
+                write_path(f, file_path)?;
+                write!(f, " {file_size}")?;
+                write_overwrite(f, *overwrite)?;
+                write_alias(f, file_path, file_name)
+            }
+            CoreCommand::FromFileToBuffer {
+                file_name,
+                buffer_name,
+                bytes_count,
+                file_start,
+                buffer_start,
+            } => write!(
+                f,
+                "from_file_to_buffer {file_name} {buffer_name} {bytes_count} {file_start} {buffer_start}"
+            ),
+            CoreCommand::FromBufferToFile {
+                buffer_name,
+                file_name,
+                bytes_count,
+                buffer_start,
+                file_start,
+            } => write!(
+                f,
+                "from_buffer_to_file {buffer_name} {file_name} {bytes_count} {buffer_start} {file_start}"
+            ),
+            CoreCommand::OpenFile {
+                file_path,
+                file_name,
+            } => {
+                write!(f, "open_file ")?;
+                write_path(f, file_path)?;
+                write_alias(f, file_path, file_name)
+            }
+            CoreCommand::OpenFileRw {
+                file_path,
+                file_name,
+                backup,
+            } => {
+                write!(f, "open_file_rw ")?;
+                write_path(f, file_path)?;
+                if *backup {
+                    write!(f, " backup")?;
+                }
+                write_alias(f, file_path, file_name)
+            }
+            CoreCommand::ShowFile {
+                file_name,
+                start,
+                end,
+                view,
+            } => {
+                write!(f, "show_file {file_name} {start} {end}")?;
+                write_view(f, *view)
+            }
+            CoreCommand::BufferWriteBytes {
+                buffer_name,
+                start,
+                bytes,
+            } => {
+                write!(f, "buffer_write_bytes {buffer_name} {start}")?;
+                bytes.iter().try_for_each(|byte| write!(f, " {byte}"))
+            }
+            CoreCommand::BufferWriteInt {
+                buffer_name,
+                index,
+                int_type,
+                endianness,
+                value,
+            } => write!(
+                f,
+                "buffer_write_int {buffer_name} {index} {int_type} {endianness} {value}"
+            ),
+            CoreCommand::BufferWriteStr {
+                buffer_name,
+                index,
+                text,
+                encoding,
+                nul,
+            } => {
+                write!(
+                    f,
+                    "buffer_write_str {buffer_name} {index} \"{}\" {encoding}",
+                    text.escape_debug()
+                )?;
+                if *nul {
+                    write!(f, " nul")?;
+                }
+                Ok(())
+            }
+            CoreCommand::BufferResize {
+                buffer_name,
+                new_size,
+            } => write!(f, "buffer_resize {buffer_name} {new_size}"),
+            CoreCommand::BufferInsert {
+                buffer_name,
+                offset,
+                bytes,
+            } => {
+                write!(f, "buffer_insert {buffer_name} {offset}")?;
+                bytes.iter().try_for_each(|byte| write!(f, " {byte}"))
+            }
+            CoreCommand::BufferDelete {
+                buffer_name,
+                start,
+                end,
+            } => write!(f, "buffer_delete {buffer_name} {start} {end}"),
+            CoreCommand::BufferAppend { buffer_name, bytes } => {
+                write!(f, "buffer_append {buffer_name}")?;
+                bytes.iter().try_for_each(|byte| write!(f, " {byte}"))
+            }
+            CoreCommand::BufferReadInt {
+                buffer_name,
+                index,
+                int_type,
+                endianness,
+            } => write!(
+                f,
+                "buffer_read_int {buffer_name} {index} {int_type} {endianness}"
+            ),
+            CoreCommand::MergeBuffers {
+                left_buffer_name,
+                right_buffer_name,
+                new_buffer_name,
+            } => write!(
+                f,
+                "merge_buffers {left_buffer_name} {right_buffer_name} {new_buffer_name}"
+            ),
+            CoreCommand::PullOutSlice {
+                buffer_name,
+                new_buffer_name,
+                start,
+                end,
+            } => write!(f, "pull_out_slice {buffer_name} {new_buffer_name} {start} {end}"),
+            CoreCommand::TurnBufferToFile {
+                buffer_name,
+                new_file_name,
+                overwrite,
+            } => {
+                write!(f, "turn_buffer_to_file {buffer_name} {new_file_name}")?;
+                write_overwrite(f, *overwrite)
+            }
+            CoreCommand::SaveBuffer {
+                buffer_name,
+                file_name,
+            } => write!(f, "save_buffer {buffer_name} {file_name}"),
+            CoreCommand::TurnFileToBuffer {
+                file_name,
+                new_buffer_name,
+            } => write!(f, "turn_file_to_buffer {file_name} {new_buffer_name}"),
+            CoreCommand::Diff {
+                left_name,
+                right_name,
+            } => write!(f, "diff {left_name} {right_name}"),
+            CoreCommand::Checksum {
+                algorithm,
+                name,
+                start,
+                end,
+            } => write!(f, "checksum {algorithm} {name} {start} {end}"),
+            CoreCommand::ChecksumWrite {
+                algorithm,
+                name,
+                start,
+                end,
+                buffer_name,
+                index,
+                layout,
+            } => {
+                write!(
+                    f,
+                    "checksum_write {algorithm} {name} {start} {end} {buffer_name} {index}"
+                )?;
+                if let Some((int_type, endianness)) = layout {
+                    write!(f, " {int_type} {endianness}")?;
+                }
+                Ok(())
+            }
+            CoreCommand::FindBytes { name, pattern } => {
+                write!(f, "find_bytes {name}")?;
+                write_pattern(f, pattern)
+            }
+            CoreCommand::FindAll { pattern } => {
+                write!(f, "find_all")?;
+                write_pattern(f, pattern)
+            }
+            CoreCommand::Let { name, value } => write!(f, "let {name} = {value}"),
+            CoreCommand::Assert { value, message } => {
+                write!(f, "assert {value}")?;
+                if let Some(message) = message {
+                    write!(f, " \"{message}\"")?;
+                }
+                Ok(())
+            }
+            CoreCommand::List => write!(f, "list"),
+            CoreCommand::Drop { name } => write!(f, "drop {name}"),
+            CoreCommand::Rename { old_name, new_name } => write!(f, "rename {old_name} {new_name}"),
+            CoreCommand::Undo => write!(f, "undo"),
+            CoreCommand::Redo => write!(f, "redo"),
+            CoreCommand::History => write!(f, "history"),
+            CoreCommand::HistorySetDepth { depth } => write!(f, "history_set_depth {depth}"),
+            CoreCommand::Set { setting } => write!(f, "set {setting}"),
+            CoreCommand::Nop => Ok(()),
+        }
+    }
+}
+
+/// The `write_pattern` function writes bytes of the pattern separated by spaces. `None` is written
+/// as `??`.
+fn write_pattern(f: &mut Formatter<'_>, pattern: &[Option<u8>]) -> FmtResult {
+    pattern.iter().try_for_each(|byte| match byte {
+        Some(byte) => write!(f, " {byte}"),
+        None => write!(f, " ??"),
+    })
+}
+
+fn write_overwrite(f: &mut Formatter<'_>, overwrite: bool) -> FmtResult {
+    if overwrite {
+        write!(f, " overwrite")?;
+    }
+    Ok(())
+}
+
+/// The `write_view` function writes the view unless it's the default one.
+fn write_view(f: &mut Formatter<'_>, view: View) -> FmtResult {
+    if view != View::Hex {
+        write!(f, " {view}")?;
+    }
+    Ok(())
+}
+
+/// The `write_path` function writes the path in double quotes if it can't be written as a word.
+fn write_path(f: &mut Formatter<'_>, path: &str) -> FmtResult {
+    if path.contains(|ch: char| ch.is_whitespace() || ch == '"' || ch == '\\') {
+        write!(f, "\"{}\"", path.escape_debug())
+    } else {
+        write!(f, "{path}")
+    }
+}
+
+/// The `write_alias` function writes `as <alias>` if the file is named differently from its path.
+fn write_alias(f: &mut Formatter<'_>, file_path: &str, file_name: &str) -> FmtResult {
+    if file_path != file_name {
+        write!(f, " as {file_name}")?;
+    }
+    Ok(())
+}
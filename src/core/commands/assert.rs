@@ -0,0 +1,17 @@
+use super::*;
+
+const DEFAULT_MESSAGE: &str = "the condition is false";
+
+/// The `assert` function returns error with given message if the value is 0. Otherwise it does
+/// nothing.
+///
+/// Output's format: nothing.
+pub fn assert(value: i128, message: Option<&str>) -> CoreResult<CoreOutput> {
+    if value == 0 {
+        return Err(CoreError::assertion_failed(
+            message.unwrap_or(DEFAULT_MESSAGE).to_string(),
+        ));
+    }
+
+    Ok(CoreOutput::new())
+}
@@ -0,0 +1,25 @@
+use super::*;
+
+fn info(buffer_name: &str, count: usize, new_size: usize) -> String {
+    format!("{count} bytes are appended to buffer {buffer_name}. Its size is {new_size} bytes.")
+}
+
+/// The `buffer_append` function writes given bytes after the end of the buffer with given name,
+/// so the buffer grows.
+///
+/// Output's format:
+/// - Info: <count> bytes are appended to buffer <buffer_name>. Its size is <size> bytes.
+pub fn buffer_append(core: &mut Core, buffer_name: &str, bytes: &[u8]) -> CoreResult<CoreOutput> {
+    let buffer = core
+        .variables
+        .get_buffer_mut(buffer_name)
+        .ok_or_else(|| CoreError::undefined_variable(buffer_name.to_string()))?;
+
+    let size = buffer.len();
+    buffer.splice_bytes(size, size, bytes);
+
+    let mut output = CoreOutput::new();
+    output.push_info(info(buffer_name, bytes.len(), buffer.len()));
+
+    Ok(output)
+}
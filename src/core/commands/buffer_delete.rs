@@ -0,0 +1,34 @@
+use super::*;
+
+fn info(buffer_name: &str, start: usize, end: usize, new_size: usize) -> String {
+    format!("Bytes {buffer_name}[{start}..{end}] are deleted. Its size is {new_size} bytes.")
+}
+
+/// The `buffer_delete` function deletes bytes of the buffer with given name starting from `start`
+/// and ending at `end`. Bytes after them are moved to `start`, so the buffer shrinks.
+///
+/// If `start` is greater than `end` or `end` goes beyond the boundaries, the function returns
+/// error.
+///
+/// Output's format:
+/// - Info: Bytes <buffer_name>[<start>..<end>] are deleted. Its size is <size> bytes.
+pub fn buffer_delete(
+    core: &mut Core,
+    buffer_name: &str,
+    start: usize,
+    end: usize,
+) -> CoreResult<CoreOutput> {
+    let buffer = core
+        .variables
+        .get_buffer_mut(buffer_name)
+        .ok_or_else(|| CoreError::undefined_variable(buffer_name.to_string()))?;
+
+    buffer
+        .splice_bytes(start, end, &[])
+        .ok_or_else(|| CoreError::out_of_range(start, end, buffer.len()))?;
+
+    let mut output = CoreOutput::new();
+    output.push_info(info(buffer_name, start, end, buffer.len()));
+
+    Ok(output)
+}
@@ -0,0 +1,35 @@
+use super::*;
+
+fn info(buffer_name: &str, offset: usize, count: usize, new_size: usize) -> String {
+    format!(
+        "{count} bytes are inserted to buffer {buffer_name} at {offset}. Its size is {new_size} bytes."
+    )
+}
+
+/// The `buffer_insert` function inserts given bytes to the buffer with given name at given
+/// offset. Bytes starting from the offset are moved to the end, so the buffer grows. The offset
+/// may equal the size of the buffer: then the bytes are appended.
+///
+/// Output's format:
+/// - Info: <count> bytes are inserted to buffer <buffer_name> at <offset>. Its size is <size>
+///   bytes.
+pub fn buffer_insert(
+    core: &mut Core,
+    buffer_name: &str,
+    offset: usize,
+    bytes: &[u8],
+) -> CoreResult<CoreOutput> {
+    let buffer = core
+        .variables
+        .get_buffer_mut(buffer_name)
+        .ok_or_else(|| CoreError::undefined_variable(buffer_name.to_string()))?;
+
+    buffer
+        .splice_bytes(offset, offset, bytes)
+        .ok_or_else(|| CoreError::incorrect_index(offset, buffer.len()))?;
+
+    let mut output = CoreOutput::new();
+    output.push_info(info(buffer_name, offset, bytes.len(), buffer.len()));
+
+    Ok(output)
+}
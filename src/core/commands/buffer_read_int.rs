@@ -0,0 +1,44 @@
+use super::*;
+
+fn info(
+    buffer_name: &str,
+    index: usize,
+    int_type: IntType,
+    endianness: Endianness,
+    value: i128,
+) -> String {
+    format!(
+        "Integer {int_type} {endianness} at index {index} in buffer '{buffer_name}' is {value}."
+    )
+}
+
+/// The `buffer_read_int` function reads an integer of given type in given order of bytes from
+/// buffer with given name starting from given index and writes it to output.
+///
+/// If the integer goes beyond the boundaries of the buffer, the function returns error.
+///
+/// Output's format:
+/// - Info: Integer <int_type> <endianness> at index <index> in buffer '<buffer_name>' is <value>.
+pub fn buffer_read_int(
+    core: &Core,
+    buffer_name: &str,
+    index: usize,
+    int_type: IntType,
+    endianness: Endianness,
+) -> CoreResult<CoreOutput> {
+    let buffer = core
+        .variables
+        .get_buffer(buffer_name)
+        .ok_or_else(|| CoreError::undefined_variable(buffer_name.to_string()))?;
+
+    let value = buffer
+        .read_int(int_type, endianness, index)
+        .ok_or_else(|| {
+            CoreError::out_of_range(index, index.saturating_add(int_type.width()), buffer.len())
+        })?;
+
+    let mut output = CoreOutput::new();
+    output.push_info(info(buffer_name, index, int_type, endianness, value));
+
+    Ok(output)
+}
@@ -0,0 +1,29 @@
+use super::*;
+
+fn info(buffer_name: &str, old_size: usize, new_size: usize) -> String {
+    format!("Buffer {buffer_name} is resized from {old_size} to {new_size} bytes.")
+}
+
+/// The `buffer_resize` function changes size of the buffer with given name. New bytes are 0;
+/// bytes beyond the new size are deleted.
+///
+/// Output's format:
+/// - Info: Buffer <buffer_name> is resized from <old_size> to <new_size> bytes.
+pub fn buffer_resize(
+    core: &mut Core,
+    buffer_name: &str,
+    new_size: usize,
+) -> CoreResult<CoreOutput> {
+    let buffer = core
+        .variables
+        .get_buffer_mut(buffer_name)
+        .ok_or_else(|| CoreError::undefined_variable(buffer_name.to_string()))?;
+
+    let old_size = buffer.len();
+    buffer.resize(new_size);
+
+    let mut output = CoreOutput::new();
+    output.push_info(info(buffer_name, old_size, new_size));
+
+    Ok(output)
+}
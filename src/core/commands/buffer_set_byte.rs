@@ -26,7 +26,7 @@ pub fn buffer_set_byte(
 ) -> CoreResult<CoreOutput> {
     let buffer = core
         .variables
-        .get_buffer_mut(&buffer_name)
+        .get_buffer_mut(buffer_name)
         .ok_or_else(|| CoreError::undefined_variable(buffer_name.to_string()))?;
 
     if !correct_index_of_buffer(buffer, index) {
@@ -7,7 +7,7 @@ fn info(buffer_name: &str, start: usize, count_of_written: usize) -> String {
 /// NABWW - Not All Bytes Were Written
 #[allow(non_snake_case)]
 fn warning_NABWW() -> String {
-    format!("Not all bytes were written.")
+    "Not all bytes were written.".to_string()
 }
 
 pub fn buffer_write_bytes(
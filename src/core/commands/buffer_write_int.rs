@@ -0,0 +1,49 @@
+use super::*;
+
+fn info(
+    buffer_name: &str,
+    index: usize,
+    int_type: IntType,
+    endianness: Endianness,
+    value: i128,
+) -> String {
+    format!("Integer {value} was written to buffer '{buffer_name}' at index {index} as {int_type} {endianness}.")
+}
+
+/// The `buffer_write_int` function writes the value as integer of given type in given order of
+/// bytes to buffer with given name starting with given index.
+///
+/// If the value doesn't fit the type, the function returns error. If the integer goes beyond the
+/// boundaries of the buffer, the function returns error and writes nothing.
+///
+/// Output's format:
+/// - Info: Integer <value> was written to buffer '<buffer_name>' at index <index> as <int_type>
+///   <endianness>.
+pub fn buffer_write_int(
+    core: &mut Core,
+    buffer_name: &str,
+    index: usize,
+    int_type: IntType,
+    endianness: Endianness,
+    value: i128,
+) -> CoreResult<CoreOutput> {
+    let buffer = core
+        .variables
+        .get_buffer_mut(buffer_name)
+        .ok_or_else(|| CoreError::undefined_variable(buffer_name.to_string()))?;
+
+    if !int_type.fits(value) {
+        return Err(CoreError::integer_overflow(value, int_type));
+    }
+
+    buffer
+        .write_int(value, int_type, endianness, index)
+        .ok_or_else(|| {
+            CoreError::out_of_range(index, index.saturating_add(int_type.width()), buffer.len())
+        })?;
+
+    let mut output = CoreOutput::new();
+    output.push_info(info(buffer_name, index, int_type, endianness, value));
+
+    Ok(output)
+}
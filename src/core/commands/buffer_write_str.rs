@@ -0,0 +1,44 @@
+use super::*;
+
+fn info(buffer_name: &str, index: usize, encoding: Encoding, count_of_written: usize) -> String {
+    format!("Text was written to buffer '{buffer_name}' at index {index} in {encoding}. Count of written bytes: {count_of_written}.")
+}
+
+/// The `buffer_write_str` function writes the text in given encoding to buffer with given name
+/// starting with given index. If `nul` is set, the text is followed by the terminating nul.
+///
+/// If the text can't be written in the encoding, the function returns error. If the text goes
+/// beyond the boundaries of the buffer, the function returns error and writes nothing.
+///
+/// Output's format:
+/// - Info: Text was written to buffer '<buffer_name>' at index <index> in <encoding>. Count of
+///   written bytes: <count_of_written>.
+pub fn buffer_write_str(
+    core: &mut Core,
+    buffer_name: &str,
+    index: usize,
+    text: &str,
+    encoding: Encoding,
+    nul: bool,
+) -> CoreResult<CoreOutput> {
+    let buffer = core
+        .variables
+        .get_buffer_mut(buffer_name)
+        .ok_or_else(|| CoreError::undefined_variable(buffer_name.to_string()))?;
+
+    let bytes = encoding
+        .encode(text, nul)
+        .ok_or_else(|| CoreError::unencodable(text.to_string(), encoding))?;
+
+    let end = index.saturating_add(bytes.len());
+    if end > buffer.len() {
+        return Err(CoreError::out_of_range(index, end, buffer.len()));
+    }
+
+    let count_of_written = buffer.write_bytes(&bytes, index);
+
+    let mut output = CoreOutput::new();
+    output.push_info(info(buffer_name, index, encoding, count_of_written));
+
+    Ok(output)
+}
@@ -0,0 +1,49 @@
+use super::*;
+
+fn info(algorithm: Algorithm, name: &str, start: usize, end: usize, checksum: &Checksum) -> String {
+    format!("Checksum {algorithm} of '{name}' in range {start}..{end} is {checksum}.")
+}
+
+/// The `compute_checksum` function computes checksum of the bytes in given range of buffer or file
+/// with given name.
+///
+/// Unlike `show_buffer`, the range isn't cut: if it goes beyond the boundaries of the variable,
+/// the function returns error. Otherwise the checksum of a part of data could be taken for the
+/// checksum of the whole range.
+pub(super) fn compute_checksum(
+    core: &mut Core,
+    algorithm: Algorithm,
+    name: &str,
+    start: usize,
+    end: usize,
+) -> CoreResult<Checksum> {
+    let bytes = read_variable(core, name)?;
+
+    if start > end || end > bytes.len() {
+        return Err(CoreError::out_of_range(start, end, bytes.len()));
+    }
+
+    Ok(algorithm.compute(&bytes[start..end]))
+}
+
+/// The `checksum` function computes checksum of the bytes in given range of buffer or file with
+/// given name and writes it to output.
+///
+/// If the range goes beyond the boundaries of the buffer or the file, the function returns error.
+///
+/// Output's format:
+/// - Info: Checksum <algorithm> of '<name>' in range <start>..<end> is <checksum>.
+pub fn checksum(
+    core: &mut Core,
+    algorithm: Algorithm,
+    name: &str,
+    start: usize,
+    end: usize,
+) -> CoreResult<CoreOutput> {
+    let checksum = compute_checksum(core, algorithm, name, start, end)?;
+
+    let mut output = CoreOutput::new();
+    output.push_info(info(algorithm, name, start, end, &checksum));
+
+    Ok(output)
+}
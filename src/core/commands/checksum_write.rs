@@ -0,0 +1,82 @@
+use super::checksum::compute_checksum;
+use super::*;
+
+fn info(algorithm: Algorithm, checksum: &Checksum, buffer_name: &str, index: usize) -> String {
+    format!(
+        "Checksum {algorithm} {checksum} was written to buffer '{buffer_name}' at index {index}."
+    )
+}
+
+/// The `checksum_write` function computes checksum of the bytes in given range of buffer or file
+/// with given name and writes it to buffer with given name starting with given index.
+///
+/// Checksums are written as integers of given type in given order of bytes. If `layout` is `None`,
+/// the smallest type the checksum fits is used in little-endian order. Hashes are written as they
+/// are, so `layout` must be `None` for them.
+///
+/// If the checksum doesn't fit the type or goes beyond the boundaries of the buffer, the function
+/// returns error and writes nothing.
+///
+/// Output's format:
+/// - Info: Checksum <algorithm> <checksum> was written to buffer '<buffer_name>' at index <index>.
+#[allow(clippy::too_many_arguments)]
+pub fn checksum_write(
+    core: &mut Core,
+    algorithm: Algorithm,
+    name: &str,
+    start: usize,
+    end: usize,
+    buffer_name: &str,
+    index: usize,
+    layout: Option<(IntType, Endianness)>,
+) -> CoreResult<CoreOutput> {
+    let checksum = compute_checksum(core, algorithm, name, start, end)?;
+
+    let buffer = core
+        .variables
+        .get_buffer_mut(buffer_name)
+        .ok_or_else(|| CoreError::undefined_variable(buffer_name.to_string()))?;
+
+    match &checksum {
+        Checksum::Int(value) => {
+            let (int_type, endianness) = layout
+                .or_else(|| {
+                    algorithm
+                        .int_type()
+                        .map(|int_type| (int_type, Endianness::Little))
+                })
+                .unwrap();
+            let value = *value as i128;
+
+            if !int_type.fits(value) {
+                return Err(CoreError::integer_overflow(value, int_type));
+            }
+
+            buffer
+                .write_int(value, int_type, endianness, index)
+                .ok_or_else(|| {
+                    CoreError::out_of_range(
+                        index,
+                        index.saturating_add(int_type.width()),
+                        buffer.len(),
+                    )
+                })?;
+        }
+        Checksum::Digest(bytes) => {
+            if index.saturating_add(bytes.len()) > buffer.len() {
+                return Err(CoreError::out_of_range(
+                    index,
+                    index.saturating_add(bytes.len()),
+                    buffer.len(),
+                ));
+            }
+
+            buffer.write_bytes(bytes, index);
+        }
+    }
+
+    let mut output = CoreOutput::new();
+    output.push_info(info(algorithm, &checksum, buffer_name, index));
+
+    Ok(output)
+}
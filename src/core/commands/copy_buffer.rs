@@ -0,0 +1,33 @@
+use super::*;
+
+/// The `copy_buffer` function copies the buffer with given name to a new buffer. If there's
+/// another variable with the new name, it's replaced.
+///
+/// Output's format:
+/// - Info: The buffer <buffer_name> is copied to <new_buffer_name>.
+/// - Warning: The <kind> <new_buffer_name> is replaced. (if there's a variable with the name)
+pub fn copy_buffer(
+    core: &mut Core,
+    buffer_name: &str,
+    new_buffer_name: String,
+) -> CoreResult<CoreOutput> {
+    let buffer = core
+        .variables
+        .get_buffer(buffer_name)
+        .ok_or_else(|| CoreError::undefined_variable(buffer_name.to_string()))?
+        .clone();
+
+    let mut output = CoreOutput::new();
+    output.push_info(format!(
+        "The buffer {buffer_name} is copied to {new_buffer_name}."
+    ));
+    if buffer_name != new_buffer_name {
+        if let Some(warning) = replacement_warning(core, &new_buffer_name) {
+            output.push_warning(warning);
+        }
+    }
+
+    core.variables.new_buffer(new_buffer_name, buffer);
+
+    Ok(output)
+}
@@ -8,18 +8,33 @@ fn create_file_info(file_name: &str, file_size: usize) -> String {
     )
 }
 
-/// The `create_file` obviously creates new file with given name and size. If there's another
-/// variable with the given name, the function just replace old value to file.
+/// The `create_file` obviously creates new file with given path and size and saves it to variables
+/// under the name `file_name`. If there's another variable with the name, the function replaces
+/// old value to file and warns about it.
+///
+/// If file with the given path already exists, the function returns error unless `overwrite` is
+/// set. If it's set, the existing file is truncated.
 ///
 /// Output's format:
 /// - Info: File with name <file_name> and size <file_size> was created.
-pub fn create_file(core: &mut Core, file_name: String, file_size: usize) -> CoreResult<CoreOutput> {
-    let file = File::New(NewFile::new(&file_name, file_size).map_err(|e| CoreError::from(e))?);
-
-    core.variables.new_file(file_name.clone(), file);
+/// - Warning: The <kind> <file_name> is replaced. (if there's a variable with the name)
+pub fn create_file(
+    core: &mut Core,
+    file_path: String,
+    file_name: String,
+    file_size: usize,
+    overwrite: bool,
+) -> CoreResult<CoreOutput> {
+    let file =
+        File::New(NewFile::create(&file_path, file_size, overwrite).map_err(CoreError::from)?);
 
     let mut output = CoreOutput::new();
     output.push_info(create_file_info(&file_name, file_size));
+    if let Some(warning) = replacement_warning(core, &file_name) {
+        output.push_warning(warning);
+    }
+
+    core.variables.new_file(file_name, file);
 
     Ok(output)
 }
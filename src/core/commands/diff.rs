@@ -0,0 +1,91 @@
+use super::*;
+
+/// The `MAX_GAP` is the greatest count of equal bytes between two differing ones that are placed
+/// into one range.
+const MAX_GAP: usize = 4;
+
+fn info(left_name: &str, right_name: &str, bytes_count: usize, ranges_count: usize) -> String {
+    format!("'{left_name}' and '{right_name}' differ in {bytes_count} bytes placed in {ranges_count} ranges.")
+}
+
+fn warning_sizes(left_name: &str, left_len: usize, right_name: &str, right_len: usize) -> String {
+    format!("Sizes differ: '{left_name}' has {left_len} bytes whereas '{right_name}' has {right_len} bytes.")
+}
+
+/// The `differing_indexes` function returns indexes where given sequences differ. If one sequence
+/// is longer than another one, all its extra bytes are considered differing.
+fn differing_indexes(left: &[u8], right: &[u8]) -> Vec<usize> {
+    (0..left.len().max(right.len()))
+        .filter(|&index| left.get(index) != right.get(index))
+        .collect()
+}
+
+/// The `coalesce` function coalesces differing indexes into ranges `[start, end)`. Indexes are
+/// placed into one range if there're no more than `MAX_GAP` indexes between them.
+///
+/// Accepted guarantees:
+/// - `indexes` are sorted.
+fn coalesce(indexes: &[usize]) -> Vec<(usize, usize)> {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+
+    for &index in indexes {
+        match ranges.last_mut() {
+            Some((_, end)) if index - *end <= MAX_GAP => *end = index + 1,
+            _ => ranges.push((index, index + 1)),
+        }
+    }
+
+    ranges
+}
+
+fn make_range(left: &[u8], right: &[u8], start: usize, end: usize) -> DiffRange {
+    DiffRange::new(
+        start,
+        (start..end).map(|index| left.get(index).copied()).collect(),
+        (start..end)
+            .map(|index| right.get(index).copied())
+            .collect(),
+    )
+}
+
+/// The `diff` function compares two buffers or files with given names and writes ranges where they
+/// differ to output.
+///
+/// Output's format:
+/// - Info: '<left_name>' and '<right_name>' differ in <bytes_count> bytes placed in <ranges_count>
+///   ranges.
+/// - Other info: Ranges where the buffers or files differ.
+/// - Warning: Sizes differ: '<left_name>' has <left_len> bytes whereas '<right_name>' has
+///   <right_len> bytes.
+pub fn diff(core: &mut Core, left_name: &str, right_name: &str) -> CoreResult<CoreOutput> {
+    let left = read_variable(core, left_name)?.into_owned();
+    let right = read_variable(core, right_name)?;
+
+    let indexes = differing_indexes(&left, &right);
+    let ranges: Vec<DiffRange> = coalesce(&indexes)
+        .into_iter()
+        .map(|(start, end)| make_range(&left, &right, start, end))
+        .collect();
+
+    let mut output = CoreOutput::new();
+    output.push_info(info(left_name, right_name, indexes.len(), ranges.len()));
+
+    if !ranges.is_empty() {
+        output.push_other_info(OtherInfo::Diff {
+            left_name: left_name.to_string(),
+            right_name: right_name.to_string(),
+            ranges,
+        });
+    }
+
+    if left.len() != right.len() {
+        output.push_warning(warning_sizes(
+            left_name,
+            left.len(),
+            right_name,
+            right.len(),
+        ));
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,21 @@
+use super::*;
+
+/// The `drop_variable` function unbinds the variable with given name. The value is moved to the
+/// journal, so the command can be undone: an opened file is closed only when the step is forgotten
+/// or the journal's depth is 0.
+///
+/// Output's format:
+/// - Info: The <kind> <name> is dropped.
+pub fn drop_variable(core: &mut Core, name: &str) -> CoreResult<CoreOutput> {
+    let kind = core
+        .variables
+        .get(name)
+        .map(VariableValue::kind)
+        .ok_or_else(|| CoreError::undefined_variable(name.to_string()))?;
+    core.variables.unbind(name);
+
+    let mut output = CoreOutput::new();
+    output.push_info(format!("The {kind} {name} is dropped."));
+
+    Ok(output)
+}
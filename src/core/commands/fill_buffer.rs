@@ -16,7 +16,7 @@ fn fill_buffer_info(buffer_name: &str, value: u8, written_bytes: usize) -> InfoL
 ///
 /// Output's format:
 /// - Info: Bytes in the amount of <written_bytes> pieces were filled by <value> value in buffer
-/// '<buffer_name>'.
+///   '<buffer_name>'.
 pub fn fill_buffer(
     core: &mut Core,
     buffer_name: &str,
@@ -0,0 +1,37 @@
+use super::*;
+
+fn info(count: usize, variables_count: usize) -> String {
+    format!("Occurrences of the pattern in the amount of {count} pieces were found in {variables_count} buffers and files.")
+}
+
+/// The `find_all` function searches all buffers and files for the pattern and writes offsets of
+/// all occurrences to output. `None` in the pattern matches any byte.
+///
+/// Output's format:
+/// - Info: Occurrences of the pattern in the amount of <count> pieces were found in
+///   <variables_count> buffers and files.
+/// - Other info: Table of names with offsets and bytes around them.
+pub fn find_all(core: &mut Core, pattern: &[Option<u8>]) -> CoreResult<CoreOutput> {
+    let mut data = Vec::new();
+    let names = core.variables.data_names();
+
+    for name in &names {
+        let bytes = read_variable(core, name)?;
+
+        data.extend(find_pattern(&bytes, pattern).into_iter().map(|offset| {
+            (
+                format!("{name}[{}]", format_offset(offset)),
+                format_context(&bytes, offset, pattern.len()),
+            )
+        }));
+    }
+
+    let mut output = CoreOutput::new();
+    output.push_info(info(data.len(), names.len()));
+
+    if !data.is_empty() {
+        output.push_other_info(OtherInfo::Table2Column { data });
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,35 @@
+use super::*;
+
+fn info(name: &str, count: usize) -> String {
+    format!("Occurrences of the pattern in the amount of {count} pieces were found in '{name}'.")
+}
+
+/// The `find_bytes` function searches the buffer or the file with given name for the pattern and
+/// writes offsets of all occurrences to output. `None` in the pattern matches any byte.
+///
+/// Output's format:
+/// - Info: Occurrences of the pattern in the amount of <count> pieces were found in '<name>'.
+/// - Other info: Table of offsets and bytes around them.
+pub fn find_bytes(core: &mut Core, name: &str, pattern: &[Option<u8>]) -> CoreResult<CoreOutput> {
+    let bytes = read_variable(core, name)?;
+    let offsets = find_pattern(&bytes, pattern);
+
+    let mut output = CoreOutput::new();
+    output.push_info(info(name, offsets.len()));
+
+    if !offsets.is_empty() {
+        let data = offsets
+            .into_iter()
+            .map(|offset| {
+                (
+                    format_offset(offset),
+                    format_context(&bytes, offset, pattern.len()),
+                )
+            })
+            .collect();
+
+        output.push_other_info(OtherInfo::Table2Column { data });
+    }
+
+    Ok(output)
+}
@@ -19,7 +19,7 @@ fn from_buffer_to_file_info(
 ///
 /// Output's format:
 /// - Info: Bytes of buffer <buffer_name> in the amount of <written_bytes_count> pieces were written to
-/// file <file_name>.
+///   file <file_name>.
 pub fn from_buffer_to_file(
     core: &mut Core,
     buffer_name: &str,
@@ -45,9 +45,10 @@ pub fn from_buffer_to_file(
 
     let written_bytes_count = match file {
         File::New(f) => f.write_bytes(&bytes, file_start),
+        File::ToWrite(f) => f.write_bytes(&bytes, file_start),
         File::ToRead(_) => return Err(CoreError::writing_to_read_only_file(file_name.to_string())),
     }
-    .map_err(|e| CoreError::from(e))?;
+    .map_err(CoreError::from)?;
 
     let mut output = CoreOutput::new();
     output.push_info(from_buffer_to_file_info(
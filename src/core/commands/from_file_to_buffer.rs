@@ -19,7 +19,7 @@ fn from_file_to_buffer_info(
 ///
 /// Output's format:
 /// - Info: Bytes of file <file_name> in the amount of <written_bytes_count> pieces were written to
-/// buffer <buffer_name>.
+///   buffer <buffer_name>.
 pub fn from_file_to_buffer(
     core: &mut Core,
     file_name: &str,
@@ -32,17 +32,12 @@ pub fn from_file_to_buffer(
         .variables
         .get_file_mut(file_name)
         .ok_or_else(|| CoreError::undefined_variable(file_name.to_string()))?;
-    let file_len = match file {
-        File::New(f) => f.len().map_err(|e| CoreError::from(e))?,
-        File::ToRead(f) => f.len().map_err(|e| CoreError::from(e))?,
-    };
+    let file_len = file.len().map_err(CoreError::from)?;
 
-    let bytes = match file {
-        File::New(f) => f.read_bytes(file_start, file_start + bytes_count),
-        File::ToRead(f) => f.read_bytes(file_start, file_start + bytes_count),
-    }
-    .map_err(|e| CoreError::from(e))?
-    .ok_or_else(|| CoreError::incorrect_index(file_start, file_len))?; // Incorrect index
+    let bytes = file
+        .read_bytes(file_start, file_start + bytes_count)
+        .map_err(CoreError::from)?
+        .ok_or_else(|| CoreError::incorrect_index(file_start, file_len))?; // Incorrect index
 
     let buffer = core
         .variables
@@ -0,0 +1,36 @@
+use super::*;
+
+const UNDONE_MARK: &str = "(undone)";
+
+fn info(steps_count: usize, depth: usize) -> String {
+    format!("Steps in the amount of {steps_count} pieces are recorded. The depth is {depth}.")
+}
+
+/// The `history` function writes steps recorded in the core's journal to output. Steps are listed
+/// from the oldest to the newest; undone steps that can be redone are marked.
+///
+/// Output's format:
+/// - Info: Steps in the amount of <steps_count> pieces are recorded. The depth is <depth>.
+/// - Other info: Table of steps.
+pub fn history(core: &Core) -> CoreResult<CoreOutput> {
+    let done = core.journal.done().map(|description| description.to_string());
+    let undone = core
+        .journal
+        .undone()
+        .map(|description| format!("{description} {UNDONE_MARK}"));
+
+    let data: Vec<(String, String)> = done
+        .chain(undone)
+        .enumerate()
+        .map(|(index, description)| ((index + 1).to_string(), description))
+        .collect();
+
+    let mut output = CoreOutput::new();
+    output.push_info(info(data.len(), core.journal.depth()));
+
+    if !data.is_empty() {
+        output.push_other_info(OtherInfo::Table2Column { data });
+    }
+
+    Ok(output)
+}
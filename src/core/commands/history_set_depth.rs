@@ -0,0 +1,19 @@
+use super::*;
+
+fn info(depth: usize) -> String {
+    format!("The history depth was set to {depth}.")
+}
+
+/// The `history_set_depth` function changes count of steps the core's journal keeps. If there're
+/// more recorded steps than the new depth, the oldest ones are forgotten.
+///
+/// Output's format:
+/// - Info: The history depth was set to <depth>.
+pub fn history_set_depth(core: &mut Core, depth: usize) -> CoreResult<CoreOutput> {
+    core.journal.set_depth(depth);
+
+    let mut output = CoreOutput::new();
+    output.push_info(info(depth));
+
+    Ok(output)
+}
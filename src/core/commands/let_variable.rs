@@ -0,0 +1,19 @@
+use super::*;
+
+fn info(name: &str, value: i128) -> String {
+    format!("Integer variable {name} is set to {value}.")
+}
+
+/// The `let_variable` function binds given integer to given name. An old variable with the name
+/// is replaced even if it's a buffer or a file.
+///
+/// Output's format:
+/// - Info: Integer variable <name> is set to <value>.
+pub fn let_variable(core: &mut Core, name: String, value: i128) -> CoreResult<CoreOutput> {
+    let mut output = CoreOutput::new();
+    output.push_info(info(&name, value));
+
+    core.variables.new_integer(name, value);
+
+    Ok(output)
+}
@@ -0,0 +1,44 @@
+use super::*;
+
+/// The `describe` function returns what the `list` command shows about a variable: its kind, its
+/// size and, for files, the path and how the file is opened.
+fn describe(value: &VariableValue) -> CoreResult<String> {
+    Ok(match value {
+        VariableValue::Buffer(buffer) => format!("buffer, {} bytes", buffer.len()),
+        VariableValue::File(file) => format!(
+            "file {}, {} bytes, {}",
+            file.path().display(),
+            file.len().map_err(CoreError::from)?,
+            file.mode()
+        ),
+        VariableValue::Integer(integer) => format!("integer {integer}"),
+    })
+}
+
+/// The `list_variables` function shows all variables in alphabetical order.
+///
+/// Output's format:
+/// - Other info: Table2Column (<name>, <kind and size>). For files, the path and the mode
+///   (`read` or `read-write`) are shown too.
+/// - Info: There're no variables. (if there're no variables)
+pub fn list_variables(core: &Core) -> CoreResult<CoreOutput> {
+    let mut output = CoreOutput::new();
+    let names = core.variables.names();
+
+    if names.is_empty() {
+        output.push_info("There're no variables.".to_string());
+        return Ok(output);
+    }
+
+    let data = names
+        .into_iter()
+        .map(|name| {
+            let description = describe(core.variables.get(&name).unwrap())?;
+            Ok((name, description))
+        })
+        .collect::<CoreResult<Vec<_>>>()?;
+
+    output.push_other_info(OtherInfo::Table2Column { data });
+
+    Ok(output)
+}
@@ -9,10 +9,11 @@ fn make_buffer_info(buffer_name: &str, buffer_size: usize) -> InfoLine {
 }
 
 /// The `make_buffer` function creates new buffer with given size and writes it to variable with
-/// given name.
+/// given name. If there's another variable with the name, it's replaced.
 ///
 /// Output's format:
 /// - Info: Buffer with name <buffer_name> and size <size> is created.
+/// - Warning: The <kind> <buffer_name> is replaced. (if there's a variable with the name)
 pub fn make_buffer(
     core: &mut Core,
     buffer_name: String,
@@ -22,6 +23,9 @@ pub fn make_buffer(
 
     let mut output = CoreOutput::new();
     output.push_info(make_buffer_info(&buffer_name, buffer_size));
+    if let Some(warning) = replacement_warning(core, &buffer_name) {
+        output.push_warning(warning);
+    }
 
     core.variables.new_buffer(buffer_name, buffer);
 
@@ -1,26 +1,161 @@
 use super::*;
 
-const COLUMNS_COUNT: usize = 16;
-
-/// The `make_table` creates table with appropriate names for columns and rows. It's take two
-/// arguments: bytes and index those bytes start with.
-pub fn make_table(bytes: &[u8], start: usize) -> OtherInfo {
-    let column_names: Vec<String> = (0..COLUMNS_COUNT).map(|c| c.to_string()).collect();
-    let row_names = (start / COLUMNS_COUNT..(start + bytes.len()) / COLUMNS_COUNT + 1)
-        .map(|row_index| (row_index * COLUMNS_COUNT).to_string())
-        .collect();
+const EMPTY_CHAR: char = ' ';
+const NON_PRINTABLE_CHAR: char = '.';
+
+/// The `byte_char` function returns the byte as character in the column of characters. Bytes that
+/// aren't printable ASCII characters are shown as dots.
+fn byte_char(byte: u8) -> char {
+    match byte {
+        0x20..=0x7E => byte as char,
+        _ => NON_PRINTABLE_CHAR,
+    }
+}
 
-    let mut table = Table::new_filled(row_names, column_names, "  ".to_string());
+/// The `incomplete_value_warning` function returns warning if the last bytes don't make a whole
+/// value of the view, so they aren't shown.
+pub fn incomplete_value_warning(bytes: &[u8], view: View) -> Option<Warning> {
+    let rest = bytes.len() % view.width();
 
-    let offset = start % COLUMNS_COUNT;
-    for (byte_index, &byte) in bytes.into_iter().enumerate() {
-        table.write(
-            // byte.to_string(),
-            format!("{:02x}", byte),
-            (offset + byte_index) / COLUMNS_COUNT,
-            (offset + byte_index) % COLUMNS_COUNT,
+    (rest != 0)
+        .then(|| format!("The last {rest} bytes don't make a whole {view} value and aren't shown."))
+}
+
+/// The `cell_width` function returns count of bytes in a cell of the table: hexadecimal bytes are
+/// grouped, other views have one value in a cell.
+fn cell_width(view: View, settings: &Settings) -> usize {
+    match view {
+        View::Hex => settings.group * view.width(),
+        _ => view.width(),
+    }
+}
+
+/// The `row_width` function returns count of bytes in a row of the table. The `columns` option is
+/// rounded up to whole cells.
+fn row_width(view: View, settings: &Settings) -> usize {
+    let cell_width = cell_width(view, settings);
+    settings.columns.div_ceil(cell_width) * cell_width
+}
+
+/// The `rounded_columns_warning` function returns warning if the `columns` option isn't a multiple
+/// of the cell width, so rows of the table have more bytes than it's set.
+pub fn rounded_columns_warning(view: View, settings: &Settings) -> Option<Warning> {
+    let row_width = row_width(view, settings);
+
+    (row_width != settings.columns).then(|| {
+        format!(
+            "Rows have {row_width} bytes: columns {} is rounded up to cells of {} bytes.",
+            settings.columns,
+            cell_width(view, settings)
         )
+    })
+}
+
+/// The `make_table` creates table with appropriate names for columns and rows. It's take bytes,
+/// index those bytes start with, the view of bytes and settings of the core: count of bytes in a
+/// row, count of bytes written together in one cell, the base of offsets naming rows and whether
+/// the column of characters is shown beside the table.
+///
+/// A cell of the table keeps one value of the view. Only hexadecimal bytes are grouped. Values
+/// start with offsets equal to `start` modulo their width, so they're shown even if `start` isn't
+/// aligned. Cells are aligned to the right.
+pub fn make_table(bytes: &[u8], start: usize, view: View, settings: &Settings) -> OtherInfo {
+    let value_width = view.width();
+    let cell_width = cell_width(view, settings);
+    let values_in_cell = cell_width / value_width;
+    let row_width = row_width(view, settings);
+    let cells_count = row_width / cell_width;
+
+    let end = start + bytes.len();
+    let phase = start % value_width;
+    let first_row = (start - phase) / row_width;
+    let end_row = (end - phase).div_ceil(row_width).max(first_row + 1);
+    let row_start = |row_index: usize| phase + row_index * row_width;
+
+    // Bytes out of the range are replaced by spaces, so partial groups keep their width.
+    let empty_value = " ".repeat(view.format(&vec![0; value_width]).len());
+
+    let column_names: Vec<String> = (0..cells_count)
+        .map(|c| (c * cell_width).to_string())
+        .collect();
+    let row_names = (first_row..end_row)
+        .map(|row_index| settings.offsets.format(row_start(row_index)))
+        .collect();
+
+    // The bytes of the value starting with given offset if all of them are in the range.
+    let value_bytes = |value_start: usize| {
+        value_start
+            .checked_sub(start)
+            .filter(|index| index + value_width <= bytes.len())
+            .map(|index| &bytes[index..index + value_width])
+    };
+    let byte = |byte_index: usize| {
+        byte_index
+            .checked_sub(start)
+            .and_then(|index| bytes.get(index).copied())
+    };
+
+    let mut rows = Vec::new();
+    let mut side_panel = Vec::new();
+    let mut colors = TableColors {
+        cells: Vec::new(),
+        side_panel: Vec::new(),
+    };
+
+    for row_index in first_row..end_row {
+        let mut cells = Vec::new();
+        let mut cell_classes = Vec::new();
+
+        for cell in 0..cells_count {
+            let cell_start = row_start(row_index) + cell * cell_width;
+            let values: Vec<Option<&[u8]>> = (0..values_in_cell)
+                .map(|value| value_bytes(cell_start + value * value_width))
+                .collect();
+
+            cells.push(
+                values
+                    .iter()
+                    .map(|value| {
+                        value.map_or_else(|| empty_value.clone(), |value| view.format(value))
+                    })
+                    .collect::<String>(),
+            );
+            cell_classes.push(ByteClass::of_bytes(
+                &values.into_iter().flatten().collect::<Vec<_>>().concat(),
+            ));
+        }
+
+        let row_bytes: Vec<Option<u8>> = (row_start(row_index)..row_start(row_index) + row_width)
+            .map(byte)
+            .collect();
+
+        rows.push(cells);
+        side_panel.push(
+            row_bytes
+                .iter()
+                .map(|byte| byte.map_or(EMPTY_CHAR, byte_char))
+                .collect::<String>(),
+        );
+        colors.cells.push(cell_classes);
+        colors.side_panel.push(
+            row_bytes
+                .iter()
+                .map(|byte| byte.map(ByteClass::of))
+                .collect(),
+        );
     }
 
-    OtherInfo::BigTable { table }
+    let mut table = Table::new(row_names, column_names);
+    let content_width = rows.iter().flatten().map(String::len).max().unwrap_or(0);
+    for (row, cells) in rows.into_iter().enumerate() {
+        for (cell_index, cell) in cells.into_iter().enumerate() {
+            table.write(format!("{cell:>content_width$}"), row, cell_index);
+        }
+    }
+
+    OtherInfo::BigTable {
+        table,
+        side_panel: settings.ascii.then_some(side_panel),
+        colors: (settings.color != ColorMode::Off).then_some(colors),
+    }
 }
@@ -6,7 +6,8 @@ fn info(left_buffer_name: &str, right_buffer_name: &str, new_buffer_name: &str)
 
 /// The `merge_buffers` function creates a buffer whose size is equal to sum of the another two,
 /// and writes bytes from the first buffer to first part of new buffer, and bytes from second
-/// buffer to the second part.
+/// buffer to the second part. If there's another variable with the new name, it's replaced with
+/// a warning.
 pub fn merge_buffers(
     core: &mut Core,
     left_buffer_name: &str,
@@ -32,6 +33,9 @@ pub fn merge_buffers(
 
     let mut output = CoreOutput::new();
     output.push_info(info(left_buffer_name, right_buffer_name, &new_buffer_name));
+    if let Some(warning) = replacement_warning(core, &new_buffer_name) {
+        output.push_warning(warning);
+    }
 
     core.variables.new_buffer(new_buffer_name, new_buffer);
 
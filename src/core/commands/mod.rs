@@ -1,44 +1,106 @@
 use super::buffer::Buffer;
+use super::checksum::{Algorithm, Checksum};
 use super::core::Core;
+use super::encoding::Encoding;
 use super::file::*;
+use super::integer::{Endianness, IntType};
+use super::settings::{ColorMode, Setting, Settings};
+use super::variables::VariableValue;
+use super::view::View;
 
 use super::core_e::{CoreError, CoreResult};
 use super::output::*;
 
+mod assert;
+mod buffer_append;
+mod buffer_delete;
 mod buffer_info;
+mod buffer_insert;
+mod buffer_read_int;
+mod buffer_resize;
 mod buffer_set_byte;
 mod buffer_write_bytes;
+mod buffer_write_int;
+mod buffer_write_str;
+mod checksum;
+mod checksum_write;
+mod copy_buffer;
 mod create_file;
+mod diff;
+mod drop_variable;
 mod fill_buffer;
+mod find_all;
+mod find_bytes;
 mod from_buffer_to_file;
 mod from_file_to_buffer;
+mod history;
+mod history_set_depth;
+mod let_variable;
+mod list_variables;
 mod make_buffer;
 mod merge_buffers;
 mod open_file;
+mod open_file_rw;
 mod pull_out_slice;
+mod redo;
+mod rename_variable;
+mod save_buffer;
+mod set;
 mod show_buffer;
 mod show_file;
 mod turn_buffer_to_file;
 mod turn_file_to_buffer;
+mod undo;
 
+pub use assert::*;
+pub use buffer_append::*;
+pub use buffer_delete::*;
 pub use buffer_info::*;
+pub use buffer_insert::*;
+pub use buffer_read_int::*;
+pub use buffer_resize::*;
 pub use buffer_set_byte::*;
 pub use buffer_write_bytes::*;
+pub use buffer_write_int::*;
+pub use buffer_write_str::*;
+pub use checksum::*;
+pub use checksum_write::*;
+pub use copy_buffer::*;
 pub use create_file::*;
+pub use diff::*;
+pub use drop_variable::*;
 pub use fill_buffer::*;
+pub use find_all::*;
+pub use find_bytes::*;
 pub use from_buffer_to_file::*;
 pub use from_file_to_buffer::*;
+pub use history::*;
+pub use history_set_depth::*;
+pub use let_variable::*;
+pub use list_variables::*;
 pub use make_buffer::*;
 pub use merge_buffers::*;
 pub use open_file::*;
+pub use open_file_rw::*;
 pub use pull_out_slice::*;
+pub use redo::*;
+pub use rename_variable::*;
+pub use save_buffer::*;
+pub use set::*;
 pub use show_buffer::*;
 pub use show_file::*;
 pub use turn_buffer_to_file::*;
 pub use turn_file_to_buffer::*;
+pub use undo::*;
 
 // Inner ones
 
 mod make_table;
+mod read_variable;
+mod replacement;
+mod search;
 
-use make_table::make_table;
+use make_table::{incomplete_value_warning, make_table, rounded_columns_warning};
+use read_variable::read_variable;
+use replacement::replacement_warning;
+use search::{find_pattern, format_context, format_offset};
@@ -1,17 +1,28 @@
 use super::*;
 
-fn open_file_info(file_name: &str) -> String {
-    format!("The file {file_name} is opened.")
+fn open_file_info(file_path: &str, file_name: &str) -> String {
+    match file_path == file_name {
+        true => format!("The file {file_path} is opened."),
+        false => format!("The file {file_path} is opened as {file_name}."),
+    }
 }
 
-/// The `open_file` function opens file with given name and saves it to variables. Name of file in
-/// variables table is the same as in the directory this file is keeped in.
-pub fn open_file(core: &mut Core, file_name: String) -> CoreResult<CoreOutput> {
-    let file_to_read = ReadFile::new(&file_name).map_err(|io_error| CoreError::from(io_error))?;
+/// The `open_file` function opens file with given path and saves it to variables under the name
+/// `file_name`. Unless an alias is given, the name is the same as the path. If there's another
+/// variable with the name, it's replaced.
+///
+/// Output's format:
+/// - Info: The file <file_path> is opened[ as <file_name>].
+/// - Warning: The <kind> <file_name> is replaced. (if there's a variable with the name)
+pub fn open_file(core: &mut Core, file_path: String, file_name: String) -> CoreResult<CoreOutput> {
+    let file_to_read = ReadFile::new(&file_path).map_err(CoreError::from)?;
     let file = File::ToRead(file_to_read);
 
     let mut output = CoreOutput::new();
-    output.push_info(open_file_info(&file_name));
+    output.push_info(open_file_info(&file_path, &file_name));
+    if let Some(warning) = replacement_warning(core, &file_name) {
+        output.push_warning(warning);
+    }
 
     core.variables.new_file(file_name, file);
 
@@ -0,0 +1,53 @@
+use super::*;
+
+use std::fs;
+
+const BACKUP_EXTENSION: &str = ".bak";
+
+fn open_file_rw_info(file_path: &str, file_name: &str) -> String {
+    match file_path == file_name {
+        true => format!("The file {file_path} is opened for reading and writing."),
+        false => format!("The file {file_path} is opened for reading and writing as {file_name}."),
+    }
+}
+
+fn backup_info(backup_name: &str) -> String {
+    format!("The backup copy {backup_name} is created.")
+}
+
+/// The `open_file_rw` function opens existing file with given path for reading and writing and
+/// saves it to variables under the name `file_name`. Unless an alias is given, the name is the
+/// same as the path. If there's another variable with the name, it's replaced.
+///
+/// If `backup` is set, the function copies the file to `<file_path>.bak` before opening. An old
+/// backup copy is replaced.
+///
+/// Output's format:
+/// - Info: The backup copy <file_path>.bak is created.
+/// - Info: The file <file_path> is opened for reading and writing[ as <file_name>].
+/// - Warning: The <kind> <file_name> is replaced. (if there's a variable with the name)
+pub fn open_file_rw(
+    core: &mut Core,
+    file_path: String,
+    file_name: String,
+    backup: bool,
+) -> CoreResult<CoreOutput> {
+    let mut output = CoreOutput::new();
+
+    if backup {
+        let backup_name = format!("{file_path}{BACKUP_EXTENSION}");
+        fs::copy(&file_path, &backup_name)?;
+
+        output.push_info(backup_info(&backup_name));
+    }
+
+    let file = File::ToWrite(WriteFile::new(&file_path)?);
+    output.push_info(open_file_rw_info(&file_path, &file_name));
+    if let Some(warning) = replacement_warning(core, &file_name) {
+        output.push_warning(warning);
+    }
+
+    core.variables.new_file(file_name, file);
+
+    Ok(output)
+}
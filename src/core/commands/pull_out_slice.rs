@@ -1,11 +1,5 @@
 use super::*;
 
-macro_rules! TTTT {
-    () => {
-        .ok_or_else(|| CoreError::undefined_variable(buffer_name.to_string()))?
-    };
-}
-
 fn info(buffer_name: &str, new_buffer_name: &str, start: usize, end: usize) -> String {
     format!("Slice {buffer_name}[{start}..{end}] is pulled out and named '{new_buffer_name}'")
 }
@@ -13,7 +7,8 @@ fn info(buffer_name: &str, new_buffer_name: &str, start: usize, end: usize) -> S
 /// The `pull_out_slice` copies bytes from given buffer starting and ending by given indexes to new
 /// buffer.
 ///
-/// The function creates new buffer which size exactly equals to `end - start`.
+/// The function creates new buffer which size exactly equals to `end - start`. If there's another
+/// variable with the new name, it's replaced with a warning.
 pub fn pull_out_slice(
     core: &mut Core,
     buffer_name: &str,
@@ -39,6 +34,9 @@ pub fn pull_out_slice(
 
     let mut output = CoreOutput::new();
     output.push_info(info(buffer_name, &new_buffer_name, start, end));
+    if let Some(warning) = replacement_warning(core, &new_buffer_name) {
+        output.push_warning(warning);
+    }
 
     core.variables.new_buffer(new_buffer_name, new_buffer);
 
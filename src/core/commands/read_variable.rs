@@ -0,0 +1,25 @@
+use super::*;
+
+use std::borrow::Cow;
+
+/// The `read_variable` function returns all bytes of the buffer or the file with given name. Bytes
+/// of buffer are borrowed, whereas bytes of file are read.
+pub fn read_variable<'a>(core: &'a mut Core, name: &str) -> CoreResult<Cow<'a, [u8]>> {
+    // The buffer is got twice because of the borrow checker.
+    if core.variables.get_buffer(name).is_some() {
+        let buffer = core.variables.get_buffer(name).unwrap();
+        return Ok(Cow::Borrowed(buffer.read_bytes(0, buffer.len()).unwrap()));
+    }
+
+    let file = core
+        .variables
+        .get_file_mut(name)
+        .ok_or_else(|| CoreError::undefined_variable(name.to_string()))?;
+    let file_len = file.len()?;
+
+    Ok(Cow::Owned(
+        file.read_bytes(0, file_len)?
+            .map(Vec::from)
+            .unwrap_or_default(),
+    ))
+}
@@ -0,0 +1,25 @@
+use super::*;
+
+fn info(description: &str) -> String {
+    format!("Command '{description}' was redone.")
+}
+
+fn warning_nothing_to_redo() -> String {
+    "There's nothing to redo.".to_string()
+}
+
+/// The `redo` function redoes the most recently undone step of the core's journal.
+///
+/// Output's format:
+/// - Info: Command '<command>' was redone.
+/// - Warning: There's nothing to redo.
+pub fn redo(core: &mut Core) -> CoreResult<CoreOutput> {
+    let mut output = CoreOutput::new();
+
+    match core.journal.redo(&mut core.variables) {
+        Some(description) => output.push_info(info(&description)),
+        None => output.push_warning(warning_nothing_to_redo()),
+    }
+
+    Ok(output)
+}
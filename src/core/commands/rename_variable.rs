@@ -0,0 +1,27 @@
+use super::*;
+
+/// The `rename_variable` function binds the variable with the old name to the new name. If
+/// there's another variable with the new name, it's replaced.
+///
+/// Output's format:
+/// - Info: The <kind> <old_name> is renamed to <new_name>.
+/// - Warning: The <kind> <new_name> is replaced. (if there's a variable with the new name)
+pub fn rename_variable(core: &mut Core, old_name: &str, new_name: &str) -> CoreResult<CoreOutput> {
+    if core.variables.get(old_name).is_none() {
+        return Err(CoreError::undefined_variable(old_name.to_string()));
+    }
+
+    let mut output = CoreOutput::new();
+    if old_name != new_name {
+        if let Some(warning) = replacement_warning(core, new_name) {
+            output.push_warning(warning);
+        }
+
+        core.variables.rename(old_name, new_name.to_string());
+    }
+
+    let kind = core.variables.get(new_name).unwrap().kind();
+    output.push_info(format!("The {kind} {old_name} is renamed to {new_name}."));
+
+    Ok(output)
+}
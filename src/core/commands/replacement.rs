@@ -0,0 +1,9 @@
+use super::*;
+
+/// The `replacement_warning` function returns a warning if there's a variable with given name
+/// which is going to be replaced by a new one.
+pub fn replacement_warning(core: &Core, name: &str) -> Option<Warning> {
+    core.variables
+        .get(name)
+        .map(|value| format!("The {} {name} is replaced.", value.kind()))
+}
@@ -0,0 +1,25 @@
+use super::*;
+
+fn info(buffer_name: &str, file_name: &str) -> String {
+    format!("Buffer {buffer_name} was saved to file {file_name}.")
+}
+
+/// The `save_buffer` function writes all bytes of buffer with given name to file with given name.
+/// If the file exists, it's replaced atomically: the file is either left untouched or contains all
+/// bytes of the buffer. The file isn't saved to variables.
+///
+/// Output's format:
+/// - Info: Buffer <buffer_name> was saved to file <file_name>.
+pub fn save_buffer(core: &Core, buffer_name: &str, file_name: &str) -> CoreResult<CoreOutput> {
+    let buffer = core
+        .variables
+        .get_buffer(buffer_name)
+        .ok_or_else(|| CoreError::undefined_variable(buffer_name.to_string()))?;
+
+    replace_atomically(file_name, buffer.read_bytes(0, buffer.len()).unwrap())?;
+
+    let mut output = CoreOutput::new();
+    output.push_info(info(buffer_name, file_name));
+
+    Ok(output)
+}
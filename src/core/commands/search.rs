@@ -0,0 +1,61 @@
+/// The `CONTEXT_SIZE` is count of bytes shown before and after the found bytes.
+const CONTEXT_SIZE: usize = 4;
+
+/// The `matches_at` function checks whether the pattern matches bytes starting from given index.
+/// `None` in the pattern matches any byte.
+fn matches_at(bytes: &[u8], pattern: &[Option<u8>], index: usize) -> bool {
+    bytes
+        .get(index..index + pattern.len())
+        .map(|window| {
+            window
+                .iter()
+                .zip(pattern)
+                .all(|(byte, expected)| expected.is_none_or(|expected| expected == *byte))
+        })
+        .unwrap_or(false)
+}
+
+/// The `find_pattern` function returns indexes of all occurrences of the pattern in given bytes.
+/// Occurrences may overlap. `None` in the pattern matches any byte.
+///
+/// Accepted guarantees:
+/// - `!pattern.is_empty()`.
+pub fn find_pattern(bytes: &[u8], pattern: &[Option<u8>]) -> Vec<usize> {
+    debug_assert!(!pattern.is_empty());
+
+    (0..bytes.len())
+        .filter(|&index| matches_at(bytes, pattern, index))
+        .collect()
+}
+
+/// The `format_offset` function writes offset in decimal and hexadecimal forms.
+pub fn format_offset(offset: usize) -> String {
+    format!("{offset} (0x{offset:x})")
+}
+
+/// The `format_context` function writes bytes around the found bytes. The found bytes are placed in
+/// square brackets.
+///
+/// #### Example
+/// ```text
+/// 00 00 00 00 [55 aa] 00 00 00 00
+/// ```
+pub fn format_context(bytes: &[u8], offset: usize, len: usize) -> String {
+    let hex = |bytes: &[u8]| {
+        bytes
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    let before = &bytes[offset.saturating_sub(CONTEXT_SIZE)..offset];
+    let found = &bytes[offset..offset + len];
+    let after = &bytes[offset + len..(offset + len + CONTEXT_SIZE).min(bytes.len())];
+
+    [hex(before), format!("[{}]", hex(found)), hex(after)]
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
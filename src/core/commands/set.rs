@@ -0,0 +1,22 @@
+use super::*;
+
+fn info(setting: Setting) -> String {
+    format!(
+        "The option {} is set to {}.",
+        setting.option(),
+        setting.value()
+    )
+}
+
+/// The `set` function changes the option of the core. Options aren't recorded to the journal.
+///
+/// Output's format:
+/// - Info: The option <option> is set to <value>.
+pub fn set(core: &mut Core, setting: Setting) -> CoreResult<CoreOutput> {
+    core.settings.apply(setting);
+
+    let mut output = CoreOutput::new();
+    output.push_info(info(setting));
+
+    Ok(output)
+}
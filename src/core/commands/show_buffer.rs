@@ -8,18 +8,21 @@ fn show_buffer_wrong_index(buffer_name: &str, index: usize) -> Warning {
     )
 }
 
-/// The `show_buffer` writes content of the buffer with given name to output.
+/// The `show_buffer` writes content of the buffer with given name to output in given view.
 ///
 /// Output's format: if the indexes are wrong in any way:
 /// - Warning: Buffer '<buffer_name>' doesn't have index <some_wrong_index>.
 ///
 /// Output's format: if the indexes are correct:
+/// - Warning: The last <count> bytes don't make a whole <view> value and aren't shown.
+/// - Warning: Rows have <count> bytes: columns <columns> is rounded up to cells of <count> bytes.
 /// - Other info: Table of elements.
 pub fn show_buffer(
     core: &Core,
     buffer_name: &str,
     start: usize,
     end: usize,
+    view: View,
 ) -> CoreResult<CoreOutput> {
     // - Get buffer with the name.
     // - If there's no buffer with the name, return Err.
@@ -43,9 +46,15 @@ pub fn show_buffer(
         }
     };
 
-    let table = make_table(bytes, start);
+    let table = make_table(bytes, start, view, &core.settings);
 
     let mut output = CoreOutput::new();
+    if let Some(warning) = incomplete_value_warning(bytes, view) {
+        output.push_warning(warning);
+    }
+    if let Some(warning) = rounded_columns_warning(view, &core.settings) {
+        output.push_warning(warning);
+    }
     output.push_other_info(table);
 
     Ok(output)
@@ -1,36 +1,34 @@
 use super::*;
 
 /// The `show_file` function reads bytes from the file with given name and writes to output the
-/// table with those bytes.
+/// table with those bytes in given view.
 pub fn show_file(
     core: &mut Core,
     file_name: &str,
     start: usize,
     end: usize,
+    view: View,
 ) -> CoreResult<CoreOutput> {
     let file = core
         .variables
         .get_file_mut(file_name)
         .ok_or_else(|| CoreError::undefined_variable(file_name.to_string()))?;
 
-    let bytes = match file {
-        File::ToRead(file) => {
-            let file_size = file.len().map_err(|io_err| CoreError::from(io_err))?;
-            file.read_bytes(start, end)
-                .map_err(|io_err| CoreError::from(io_err))?
-                .ok_or_else(|| CoreError::incorrect_index(start, file_size))?
-        }
-        File::New(file) => {
-            let file_size = file.len().map_err(|io_err| CoreError::from(io_err))?;
-            file.read_bytes(start, end)
-                .map_err(|io_err| CoreError::from(io_err))?
-                .ok_or_else(|| CoreError::incorrect_index(start, file_size))?
-        }
-    };
+    let file_size = file.len().map_err(CoreError::from)?;
+    let bytes = file
+        .read_bytes(start, end)
+        .map_err(CoreError::from)?
+        .ok_or_else(|| CoreError::incorrect_index(start, file_size))?;
 
-    let table = make_table(&bytes, start);
+    let table = make_table(&bytes, start, view, &core.settings);
 
     let mut output = CoreOutput::new();
+    if let Some(warning) = incomplete_value_warning(&bytes, view) {
+        output.push_warning(warning);
+    }
+    if let Some(warning) = rounded_columns_warning(view, &core.settings) {
+        output.push_warning(warning);
+    }
     output.push_other_info(table);
 
     Ok(output)
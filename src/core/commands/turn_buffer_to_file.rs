@@ -8,22 +8,29 @@ fn info(buffer_name: &str, new_file_name: &str) -> String {
 }
 
 /// The `turn_buffer_to_file` function creates file with given name and data the same as in buffer
-/// with given name.
+/// with given name. If there's another variable with the new name, it's replaced with a warning.
+///
+/// If file with the given name already exists in directory, the function returns error unless
+/// `overwrite` is set. If it's set, the existing file is truncated.
 pub fn turn_buffer_to_file(
     core: &mut Core,
     buffer_name: &str,
     new_file_name: String,
+    overwrite: bool,
 ) -> CoreResult<CoreOutput> {
     let buffer = core
         .variables
         .get_buffer(buffer_name)
         .ok_or_else(|| CoreError::undefined_variable(buffer_name.to_string()))?;
-    let mut new_file = NewFile::new(&new_file_name, buffer.len())?;
+    let mut new_file = NewFile::create(&new_file_name, buffer.len(), overwrite)?;
 
     new_file.write_bytes(buffer.read_bytes(0, buffer.len()).unwrap(), 0)?;
 
     let mut output = CoreOutput::new();
     output.push_info(info(buffer_name, &new_file_name));
+    if let Some(warning) = replacement_warning(core, &new_file_name) {
+        output.push_warning(warning);
+    }
 
     core.variables.new_file(new_file_name, File::New(new_file));
 
@@ -8,7 +8,8 @@ fn info(file_name: &str, new_buffer_name: &str) -> String {
 }
 
 /// The `turn_file_to_buffer` function turns file with given name to buffer with given name. The
-/// file keeps being in variables.
+/// file keeps being in variables unless the buffer has the same name. If there's another variable
+/// with the new name, it's replaced with a warning.
 pub fn turn_file_to_buffer(
     core: &mut Core,
     file_name: &str,
@@ -19,14 +20,8 @@ pub fn turn_file_to_buffer(
         .get_file_mut(file_name)
         .ok_or_else(|| CoreError::undefined_variable(file_name.to_string()))?;
 
-    let file_size = match file {
-        File::New(f) => f.len()?,
-        File::ToRead(f) => f.len()?,
-    };
-    let file_bytes = match file {
-        File::New(f) => f.read_bytes(0, f.len()?)?.unwrap(),
-        File::ToRead(f) => f.read_bytes(0, f.len()?)?.unwrap(),
-    };
+    let file_size = file.len()?;
+    let file_bytes = file.read_bytes(0, file_size)?.unwrap_or_default();
 
     let mut new_buffer = Buffer::new(file_size);
 
@@ -34,6 +29,9 @@ pub fn turn_file_to_buffer(
 
     let mut output = CoreOutput::new();
     output.push_info(info(file_name, &new_buffer_name));
+    if let Some(warning) = replacement_warning(core, &new_buffer_name) {
+        output.push_warning(warning);
+    }
 
     core.variables.new_buffer(new_buffer_name, new_buffer);
 
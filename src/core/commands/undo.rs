@@ -0,0 +1,25 @@
+use super::*;
+
+fn info(description: &str) -> String {
+    format!("Command '{description}' was undone.")
+}
+
+fn warning_nothing_to_undo() -> String {
+    "There's nothing to undo.".to_string()
+}
+
+/// The `undo` function undoes the newest step recorded in the core's journal.
+///
+/// Output's format:
+/// - Info: Command '<command>' was undone.
+/// - Warning: There's nothing to undo.
+pub fn undo(core: &mut Core) -> CoreResult<CoreOutput> {
+    let mut output = CoreOutput::new();
+
+    match core.journal.undo(&mut core.variables) {
+        Some(description) => output.push_info(info(&description)),
+        None => output.push_warning(warning_nothing_to_undo()),
+    }
+
+    Ok(output)
+}
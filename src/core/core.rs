@@ -1,18 +1,41 @@
 use super::command::CoreCommand;
 use super::commands::*;
-use super::core_e::CoreResult;
+use super::core_e::{CoreError, CoreResult};
+use super::expression::{evaluate_expression, evaluate_tokens};
+use super::journal::{Journal, Snapshot};
 use super::output::CoreOutput;
-use super::variables::Variables;
+use super::settings::{ColorMode, Settings};
+use super::token::Token;
+use super::variables::{VariableValue, Variables};
+
+/// The `DEFAULT_HISTORY_DEPTH` is count of steps the core's journal keeps by default.
+pub const DEFAULT_HISTORY_DEPTH: usize = 100;
 
 pub struct Core {
     pub(super) variables: Variables,
+    pub(super) journal: Journal,
+    pub(super) settings: Settings,
+}
+
+impl Default for Core {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Core {
     /// The `new` function creates empty core.
     pub fn new() -> Core {
+        Core::with_history_depth(DEFAULT_HISTORY_DEPTH)
+    }
+
+    /// The `with_history_depth` function creates empty core whose journal keeps no more than given
+    /// count of steps. If the count is 0, nothing is journaled.
+    pub fn with_history_depth(depth: usize) -> Core {
         Core {
             variables: Variables::new(),
+            journal: Journal::new(depth),
+            settings: Settings::default(),
         }
     }
 
@@ -21,7 +44,76 @@ impl Core {
     ///
     /// A returning value is `Result<CoreOutput, CoreError>`. `CoreOutput` is what the command have
     /// written. For example, `show_buffer` command writes to `CoreOutput` bunch of bytes.
+    ///
+    /// If the command changes variables and is executed successfully, the changes are recorded to
+    /// the journal, so they can be undone by the `undo` command.
     pub fn execute(&mut self, command: CoreCommand) -> CoreResult<CoreOutput> {
+        let snapshot = Snapshot::take(&self.variables, &command);
+
+        let result = self.dispatch(command);
+        // Values the command has unbound or replaced are moved to the journal. The rest of them
+        // are deleted here, so opened files are closed.
+        let released = self.variables.take_released();
+        let output = result?;
+        self.journal.record(snapshot.finish(released));
+
+        Ok(output)
+    }
+
+    /// The `evaluate` function replaces expressions in given tokens by their values, so the tokens
+    /// can be parsed then. For example, `fill_buffer mbr 0 size(mbr)-2 size(mbr)` becomes
+    /// `fill_buffer mbr 0 510 512` if the size of `mbr` is 512.
+    pub fn evaluate(&self, tokens: &[Token]) -> CoreResult<Vec<Token>> {
+        evaluate_tokens(self, tokens)
+    }
+
+    /// The `evaluate_expression` function evaluates given tokens as one expression. It's used for
+    /// conditions and bounds of loops.
+    pub fn evaluate_expression(&self, tokens: &[Token]) -> CoreResult<i128> {
+        evaluate_expression(self, tokens)
+    }
+
+    /// The `set_integer` function binds given integer to given name bypassing the journal. It's
+    /// used for variables of loops, so a buffer or a file with the name isn't replaced: the
+    /// function returns error then.
+    pub fn set_integer(&mut self, name: &str, value: i128) -> CoreResult<()> {
+        if self.is_data(name) {
+            return Err(CoreError::not_integer(name.to_string()));
+        }
+
+        self.variables
+            .replace(name.to_string(), Some(VariableValue::Integer(value)));
+        Ok(())
+    }
+
+    /// The `integer` function returns value of the integer variable with given name. If there's
+    /// no integer with the name, the function returns `None`.
+    pub fn integer(&self, name: &str) -> Option<i128> {
+        self.variables.get_integer(name)
+    }
+
+    /// The `restore_integer` function binds given integer to given name or unbinds the name if
+    /// there's no integer. It's used to restore the variable a loop shadowed. A buffer or a file
+    /// with the name is kept.
+    pub fn restore_integer(&mut self, name: &str, value: Option<i128>) {
+        if self.is_data(name) {
+            return;
+        }
+
+        self.variables
+            .replace(name.to_string(), value.map(VariableValue::Integer));
+    }
+
+    /// The `is_data` function checks whether given name is bound to a buffer or a file.
+    fn is_data(&self, name: &str) -> bool {
+        matches!(
+            self.variables.get(name),
+            Some(VariableValue::Buffer(_) | VariableValue::File(_))
+        )
+    }
+
+    /// The `dispatch` function calls function appropriate to given command.
+    fn dispatch(&mut self, command: CoreCommand) -> CoreResult<CoreOutput> {
         match command {
             CoreCommand::MakeBuffer {
                 buffer_name,
@@ -37,12 +129,19 @@ impl Core {
                 buffer_name,
                 start,
                 end,
-            } => show_buffer(self, buffer_name, start, end),
+                view,
+            } => show_buffer(self, buffer_name, start, end, view),
             CoreCommand::BufferInfo { buffer_name } => buffer_info(self, buffer_name),
+            CoreCommand::CopyBuffer {
+                buffer_name,
+                new_buffer_name,
+            } => copy_buffer(self, buffer_name, new_buffer_name),
             CoreCommand::CreateFile {
+                file_path,
                 file_name,
                 file_size,
-            } => create_file(self, file_name, file_size),
+                overwrite,
+            } => create_file(self, file_path, file_name, file_size, overwrite),
             CoreCommand::BufferSetByte {
                 buffer_name,
                 index,
@@ -76,17 +175,63 @@ impl Core {
                 buffer_start,
                 file_start,
             ),
-            CoreCommand::OpenFile { file_name } => open_file(self, file_name),
+            CoreCommand::OpenFile {
+                file_path,
+                file_name,
+            } => open_file(self, file_path, file_name),
+            CoreCommand::OpenFileRw {
+                file_path,
+                file_name,
+                backup,
+            } => open_file_rw(self, file_path, file_name, backup),
             CoreCommand::ShowFile {
                 file_name,
                 start,
                 end,
-            } => show_file(self, file_name, start, end),
+                view,
+            } => show_file(self, file_name, start, end, view),
             CoreCommand::BufferWriteBytes {
                 buffer_name,
                 start,
                 bytes,
             } => buffer_write_bytes(self, buffer_name, start, &bytes),
+            CoreCommand::BufferWriteInt {
+                buffer_name,
+                index,
+                int_type,
+                endianness,
+                value,
+            } => buffer_write_int(self, buffer_name, index, int_type, endianness, value),
+            CoreCommand::BufferWriteStr {
+                buffer_name,
+                index,
+                text,
+                encoding,
+                nul,
+            } => buffer_write_str(self, buffer_name, index, text, encoding, nul),
+            CoreCommand::BufferResize {
+                buffer_name,
+                new_size,
+            } => buffer_resize(self, buffer_name, new_size),
+            CoreCommand::BufferInsert {
+                buffer_name,
+                offset,
+                bytes,
+            } => buffer_insert(self, buffer_name, offset, &bytes),
+            CoreCommand::BufferDelete {
+                buffer_name,
+                start,
+                end,
+            } => buffer_delete(self, buffer_name, start, end),
+            CoreCommand::BufferAppend { buffer_name, bytes } => {
+                buffer_append(self, buffer_name, &bytes)
+            }
+            CoreCommand::BufferReadInt {
+                buffer_name,
+                index,
+                int_type,
+                endianness,
+            } => buffer_read_int(self, buffer_name, index, int_type, endianness),
             CoreCommand::MergeBuffers {
                 left_buffer_name,
                 right_buffer_name,
@@ -101,15 +246,72 @@ impl Core {
             CoreCommand::TurnBufferToFile {
                 buffer_name,
                 new_file_name,
-            } => turn_buffer_to_file(self, buffer_name, new_file_name),
+                overwrite,
+            } => turn_buffer_to_file(self, buffer_name, new_file_name, overwrite),
+            CoreCommand::SaveBuffer {
+                buffer_name,
+                file_name,
+            } => save_buffer(self, buffer_name, file_name),
             CoreCommand::TurnFileToBuffer {
                 file_name,
                 new_buffer_name,
             } => turn_file_to_buffer(self, file_name, new_buffer_name),
+            CoreCommand::Diff {
+                left_name,
+                right_name,
+            } => diff(self, left_name, right_name),
+            CoreCommand::Checksum {
+                algorithm,
+                name,
+                start,
+                end,
+            } => checksum(self, algorithm, name, start, end),
+            CoreCommand::ChecksumWrite {
+                algorithm,
+                name,
+                start,
+                end,
+                buffer_name,
+                index,
+                layout,
+            } => checksum_write(
+                self,
+                algorithm,
+                name,
+                start,
+                end,
+                buffer_name,
+                index,
+                layout,
+            ),
+            CoreCommand::FindBytes { name, pattern } => find_bytes(self, name, &pattern),
+            CoreCommand::FindAll { pattern } => find_all(self, &pattern),
+            CoreCommand::Let { name, value } => let_variable(self, name, value),
+            CoreCommand::Assert { value, message } => assert(value, message),
+            CoreCommand::List => list_variables(self),
+            CoreCommand::Drop { name } => drop_variable(self, name),
+            CoreCommand::Rename { old_name, new_name } => rename_variable(self, old_name, new_name),
+            CoreCommand::Undo => undo(self),
+            CoreCommand::Redo => redo(self),
+            CoreCommand::History => history(self),
+            CoreCommand::HistorySetDepth { depth } => history_set_depth(self, depth),
+            CoreCommand::Set { setting } => set(self, setting),
             CoreCommand::Nop => Ok(CoreOutput::new()),
         }
     }
 
+    /// The `color_mode` function returns the `color` option. Output of the core is colored by
+    /// the terminal.
+    pub fn color_mode(&self) -> ColorMode {
+        self.settings.color
+    }
+
+    /// The `variable_names` function returns names of all buffers, files and integer variables in
+    /// alphabetical order.
+    pub fn variable_names(&self) -> Vec<String> {
+        self.variables.names()
+    }
+
     /// The `buffer_size` function returns size of the buffer with given name. If there's no buffer
     /// with the name, the function returns `None`.
     pub fn buffer_size(&self, buffer_name: &str) -> Option<usize> {
@@ -117,4 +319,180 @@ impl Core {
             .get_buffer(buffer_name)
             .map(|buffer| buffer.len())
     }
+
+    /// The `buffer_bytes` function returns all bytes of the buffer with given name. If there's no
+    /// buffer with the name, the function returns `None`.
+    pub fn buffer_bytes(&self, buffer_name: &str) -> Option<&[u8]> {
+        self.variables
+            .get_buffer(buffer_name)
+            .and_then(|buffer| buffer.read_bytes(0, buffer.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn core_with_buffer(bytes: &[u8]) -> Core {
+        let mut core = Core::new();
+        core.execute(CoreCommand::MakeBuffer {
+            buffer_name: "b".to_string(),
+            buffer_size: bytes.len(),
+        })
+        .unwrap();
+        core.execute(CoreCommand::BufferWriteBytes {
+            buffer_name: "b",
+            start: 0,
+            bytes: bytes.to_vec(),
+        })
+        .unwrap();
+        core
+    }
+
+    fn error(core: &mut Core, command: CoreCommand) -> String {
+        match core.execute(command) {
+            Ok(_) => panic!("the command must fail"),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn buffer_insert_bounds() {
+        let mut core = core_with_buffer(&[1, 2, 3]);
+        let insert = |offset| CoreCommand::BufferInsert {
+            buffer_name: "b",
+            offset,
+            bytes: vec![7],
+        };
+
+        assert_eq!(
+            error(&mut core, insert(4)),
+            "CoreError: IncorrectIndex: 4 whereas the top is 3"
+        );
+        core.execute(insert(3)).unwrap();
+        assert_eq!(core.buffer_bytes("b"), Some(&[1, 2, 3, 7][..]));
+    }
+
+    #[test]
+    fn buffer_delete_bounds() {
+        let mut core = core_with_buffer(&[1, 2, 3]);
+        let delete = |start, end| CoreCommand::BufferDelete {
+            buffer_name: "b",
+            start,
+            end,
+        };
+
+        assert_eq!(
+            error(&mut core, delete(2, 1)),
+            "CoreError: OutOfRange: 2..1 whereas the top is 3"
+        );
+        assert_eq!(
+            error(&mut core, delete(1, 4)),
+            "CoreError: OutOfRange: 1..4 whereas the top is 3"
+        );
+        core.execute(delete(0, 3)).unwrap();
+        assert_eq!(core.buffer_bytes("b"), Some(&[][..]));
+    }
+
+    #[test]
+    fn resizing_undefined_buffer() {
+        let mut core = core_with_buffer(&[1, 2, 3]);
+        let commands = [
+            CoreCommand::BufferResize {
+                buffer_name: "c",
+                new_size: 4,
+            },
+            CoreCommand::BufferInsert {
+                buffer_name: "c",
+                offset: 0,
+                bytes: vec![7],
+            },
+            CoreCommand::BufferDelete {
+                buffer_name: "c",
+                start: 0,
+                end: 0,
+            },
+            CoreCommand::BufferAppend {
+                buffer_name: "c",
+                bytes: vec![7],
+            },
+        ];
+
+        for command in commands {
+            assert_eq!(error(&mut core, command), "CoreError: UndefinedVariable: c");
+        }
+        assert_eq!(core.journal.done().count(), 2);
+    }
+
+    #[test]
+    fn undo_and_redo_resizing() {
+        let mut core = core_with_buffer(&[1, 2, 3, 4]);
+        let commands = [
+            CoreCommand::BufferInsert {
+                buffer_name: "b",
+                offset: 1,
+                bytes: vec![7, 8],
+            },
+            CoreCommand::BufferDelete {
+                buffer_name: "b",
+                start: 4,
+                end: 6,
+            },
+            CoreCommand::BufferResize {
+                buffer_name: "b",
+                new_size: 6,
+            },
+            CoreCommand::BufferAppend {
+                buffer_name: "b",
+                bytes: vec![9],
+            },
+            CoreCommand::BufferResize {
+                buffer_name: "b",
+                new_size: 2,
+            },
+        ];
+        let mut states = vec![core.buffer_bytes("b").unwrap().to_vec()];
+        for command in commands {
+            core.execute(command).unwrap();
+            states.push(core.buffer_bytes("b").unwrap().to_vec());
+        }
+        assert_eq!(states[3], [1, 7, 8, 2, 0, 0]);
+        assert_eq!(states[5], [1, 7]);
+
+        for state in states.iter().rev().skip(1) {
+            core.execute(CoreCommand::Undo).unwrap();
+            assert_eq!(core.buffer_bytes("b").unwrap(), state);
+        }
+        for state in states.iter().skip(1) {
+            core.execute(CoreCommand::Redo).unwrap();
+            assert_eq!(core.buffer_bytes("b").unwrap(), state);
+        }
+    }
+
+    #[test]
+    fn undo_and_redo_bindings() {
+        let mut core = core_with_buffer(&[1, 2]);
+        core.execute(CoreCommand::MakeBuffer {
+            buffer_name: "c".to_string(),
+            buffer_size: 3,
+        })
+        .unwrap();
+        core.execute(CoreCommand::Rename {
+            old_name: "b",
+            new_name: "c",
+        })
+        .unwrap();
+        core.execute(CoreCommand::Drop { name: "c" }).unwrap();
+        assert_eq!(core.variable_names(), Vec::<String>::new());
+
+        core.execute(CoreCommand::Undo).unwrap();
+        assert_eq!(core.buffer_bytes("c"), Some(&[1, 2][..]));
+        core.execute(CoreCommand::Undo).unwrap();
+        assert_eq!(core.buffer_bytes("b"), Some(&[1, 2][..]));
+        assert_eq!(core.buffer_size("c"), Some(3));
+
+        core.execute(CoreCommand::Redo).unwrap();
+        core.execute(CoreCommand::Redo).unwrap();
+        assert_eq!(core.variable_names(), Vec::<String>::new());
+    }
 }
@@ -2,11 +2,22 @@ use std::error::Error as ErrorTrait;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::io::Error as IoError;
 
+use super::encoding::Encoding;
+use super::integer::IntType;
+
 #[derive(Debug)]
 enum CoreErrorEnum {
     UndefinedVariable { variable_name: String },
     IncorrectIndex { index: usize, top: usize },
     WritingToReadOnlyFile { file_name: String },
+    IntegerOverflow { value: i128, int_type: IntType },
+    OutOfRange { start: usize, end: usize, top: usize },
+    InvalidExpression { description: String },
+    DivisionByZero,
+    ArithmeticOverflow,
+    AssertionFailed { message: String },
+    Unencodable { text: String, encoding: Encoding },
+    NotInteger { variable_name: String },
     Io { e: IoError },
 }
 
@@ -22,6 +33,37 @@ impl Display for CoreErrorEnum {
             CoreErrorEnum::WritingToReadOnlyFile { file_name } => {
                 write!(f, "WritingToReadOnlyFile: {}", file_name)
             }
+            CoreErrorEnum::IntegerOverflow { value, int_type } => {
+                write!(
+                    f,
+                    "IntegerOverflow: {} doesn't fit {} whose range is {}..={}",
+                    value,
+                    int_type,
+                    int_type.min(),
+                    int_type.max()
+                )
+            }
+            CoreErrorEnum::OutOfRange { start, end, top } => {
+                write!(f, "OutOfRange: {}..{} whereas the top is {}", start, end, top)
+            }
+            CoreErrorEnum::InvalidExpression { description } => {
+                write!(f, "InvalidExpression: {}", description)
+            }
+            CoreErrorEnum::DivisionByZero => {
+                write!(f, "DivisionByZero")
+            }
+            CoreErrorEnum::ArithmeticOverflow => {
+                write!(f, "ArithmeticOverflow")
+            }
+            CoreErrorEnum::AssertionFailed { message } => {
+                write!(f, "AssertionFailed: {}", message)
+            }
+            CoreErrorEnum::Unencodable { text, encoding } => {
+                write!(f, "Unencodable: \"{}\" can't be written in {}", text, encoding)
+            }
+            CoreErrorEnum::NotInteger { variable_name } => {
+                write!(f, "NotInteger: {} is a buffer or a file", variable_name)
+            }
             CoreErrorEnum::Io { e } => {
                 write!(f, "{}", e)
             }
@@ -54,6 +96,54 @@ impl CoreError {
             enumer: CoreErrorEnum::WritingToReadOnlyFile { file_name },
         }
     }
+
+    pub fn integer_overflow(value: i128, int_type: IntType) -> Self {
+        CoreError {
+            enumer: CoreErrorEnum::IntegerOverflow { value, int_type },
+        }
+    }
+
+    pub fn out_of_range(start: usize, end: usize, top: usize) -> Self {
+        CoreError {
+            enumer: CoreErrorEnum::OutOfRange { start, end, top },
+        }
+    }
+
+    pub fn invalid_expression(description: String) -> Self {
+        CoreError {
+            enumer: CoreErrorEnum::InvalidExpression { description },
+        }
+    }
+
+    pub fn division_by_zero() -> Self {
+        CoreError {
+            enumer: CoreErrorEnum::DivisionByZero,
+        }
+    }
+
+    pub fn arithmetic_overflow() -> Self {
+        CoreError {
+            enumer: CoreErrorEnum::ArithmeticOverflow,
+        }
+    }
+
+    pub fn assertion_failed(message: String) -> Self {
+        CoreError {
+            enumer: CoreErrorEnum::AssertionFailed { message },
+        }
+    }
+
+    pub fn unencodable(text: String, encoding: Encoding) -> Self {
+        CoreError {
+            enumer: CoreErrorEnum::Unencodable { text, encoding },
+        }
+    }
+
+    pub fn not_integer(variable_name: String) -> Self {
+        CoreError {
+            enumer: CoreErrorEnum::NotInteger { variable_name },
+        }
+    }
 }
 
 impl Display for CoreError {
@@ -0,0 +1,66 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The `Encoding` enumeration contains all encodings text can be written to buffers in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Ascii,
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+}
+
+impl Encoding {
+    /// The `from_name` function returns the encoding with given name: `ascii`, `utf8`, `utf16le`
+    /// or `utf16be`. If there's no encoding with the name, the function returns `None`.
+    pub fn from_name(name: &str) -> Option<Encoding> {
+        match name {
+            "ascii" => Some(Encoding::Ascii),
+            "utf8" => Some(Encoding::Utf8),
+            "utf16le" => Some(Encoding::Utf16Le),
+            "utf16be" => Some(Encoding::Utf16Be),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Encoding::Ascii => "ascii",
+            Encoding::Utf8 => "utf8",
+            Encoding::Utf16Le => "utf16le",
+            Encoding::Utf16Be => "utf16be",
+        }
+    }
+
+    /// The `unit_width` function returns count of bytes of one code unit. The terminating nul
+    /// takes one unit.
+    pub fn unit_width(&self) -> usize {
+        match self {
+            Encoding::Ascii | Encoding::Utf8 => 1,
+            Encoding::Utf16Le | Encoding::Utf16Be => 2,
+        }
+    }
+
+    /// The `encode` function turns the text into bytes. If `nul` is set, the terminating nul is
+    /// appended. If the text can't be written in the encoding (non-ASCII characters in ASCII),
+    /// the function returns `None`.
+    pub fn encode(&self, text: &str, nul: bool) -> Option<Vec<u8>> {
+        let mut bytes = match self {
+            Encoding::Ascii if !text.is_ascii() => return None,
+            Encoding::Ascii | Encoding::Utf8 => text.as_bytes().to_vec(),
+            Encoding::Utf16Le => text.encode_utf16().flat_map(u16::to_le_bytes).collect(),
+            Encoding::Utf16Be => text.encode_utf16().flat_map(u16::to_be_bytes).collect(),
+        };
+
+        if nul {
+            bytes.resize(bytes.len() + self.unit_width(), 0);
+        }
+
+        Some(bytes)
+    }
+}
+
+impl Display for Encoding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
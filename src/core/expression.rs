@@ -0,0 +1,398 @@
+use super::core::Core;
+use super::core_e::{CoreError, CoreResult};
+use super::registry::{find_command, ArgumentKind, CommandInfo};
+use super::token::Token;
+
+const SIZE_FUNCTION: &str = "size";
+
+/// Binary operators from the lowest priority to the highest one.
+const BINARY_OPERATORS: [&[&str]; 5] = [
+    &["||"],
+    &["&&"],
+    &["==", "!=", "<", "<=", ">", ">="],
+    &["+", "-"],
+    &["*", "/", "%"],
+];
+
+/// The `Evaluator` structure evaluates expressions in a sequence of tokens.
+///
+/// The grammar of expressions is the following one:
+/// ```text
+/// expression = binary(0)
+/// binary(n)  = binary(n + 1) (<operator of level n> binary(n + 1))*
+/// unary      = ("-" | "!") unary | primary
+/// primary    = <integer> | <integer variable> | "size" "(" <name> ")" | "(" expression ")"
+/// ```
+/// Comparisons and logical operators give 1 for truth and 0 for falsehood. `&&` and `||` don't
+/// evaluate the right operand if the left one determines the result.
+struct Evaluator<'a, 't> {
+    core: &'a Core,
+    tokens: &'t [Token],
+    position: usize,
+    /// If it's not set, operands are only parsed. That's for the right operand of `&&` and `||`.
+    evaluating: bool,
+}
+
+impl<'a, 't> Evaluator<'a, 't> {
+    fn new(core: &'a Core, tokens: &'t [Token]) -> Self {
+        Evaluator {
+            core,
+            tokens,
+            position: 0,
+            evaluating: true,
+        }
+    }
+
+    fn peek(&self) -> Option<&'t Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn peek_punct(&self) -> Option<&'static str> {
+        match self.peek()? {
+            Token::Punct(punct) => Some(punct),
+            _ => None,
+        }
+    }
+
+    fn expect_punct(&mut self, punct: &str) -> CoreResult<()> {
+        if self.peek_punct() != Some(punct) {
+            return Err(CoreError::invalid_expression(format!(
+                "'{punct}' is expected"
+            )));
+        }
+
+        self.position += 1;
+        Ok(())
+    }
+
+    /// The `is_integer_variable` function checks whether the token is a name of integer variable.
+    fn is_integer_variable(&self, token: &Token) -> bool {
+        matches!(token, Token::Word(name) if self.core.variables.get_integer(name).is_some())
+    }
+
+    /// The `is_function_call` function checks whether an expression's function is called at given
+    /// position.
+    fn is_function_call(&self, position: usize) -> bool {
+        matches!(self.tokens.get(position), Some(Token::Word(name)) if name == SIZE_FUNCTION)
+            && self.tokens.get(position + 1) == Some(&Token::Punct("("))
+    }
+
+    /// The `starts_expression` function checks whether an expression starts at current position.
+    ///
+    /// A word followed by an operator starts expression even if it isn't a name of integer
+    /// variable. So the mistake is reported as undefined variable.
+    fn starts_expression(&self) -> bool {
+        let followed_by_operator = matches!(
+            self.tokens.get(self.position + 1),
+            Some(Token::Punct(punct)) if is_binary_operator(punct)
+        );
+
+        match self.peek() {
+            Some(Token::UInt(_) | Token::SInt(_) | Token::Punct("(" | "-" | "!")) => true,
+            Some(Token::Word(_)) if followed_by_operator => true,
+            Some(token) => self.is_integer_variable(token) || self.is_function_call(self.position),
+            None => false,
+        }
+    }
+
+    fn expression(&mut self) -> CoreResult<i128> {
+        self.binary(0)
+    }
+
+    fn binary(&mut self, level: usize) -> CoreResult<i128> {
+        let Some(operators) = BINARY_OPERATORS.get(level) else {
+            return self.unary();
+        };
+
+        let mut value = self.binary(level + 1)?;
+
+        while let Some(operator) = self.peek_punct().filter(|punct| operators.contains(punct)) {
+            self.position += 1;
+
+            let short_circuit = match operator {
+                "&&" => value == 0,
+                "||" => value != 0,
+                _ => false,
+            };
+            let evaluating = self.evaluating;
+            self.evaluating = evaluating && !short_circuit;
+
+            let right = self.binary(level + 1);
+            self.evaluating = evaluating;
+            let right = right?;
+
+            if self.evaluating {
+                value = apply_binary_operator(operator, value, right)?;
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn unary(&mut self) -> CoreResult<i128> {
+        match self.peek_punct() {
+            Some("-") => {
+                self.position += 1;
+                self.unary()?
+                    .checked_neg()
+                    .ok_or_else(CoreError::arithmetic_overflow)
+            }
+            Some("!") => {
+                self.position += 1;
+                Ok((self.unary()? == 0) as i128)
+            }
+            _ => self.primary(),
+        }
+    }
+
+    fn primary(&mut self) -> CoreResult<i128> {
+        if self.is_function_call(self.position) {
+            return self.size();
+        }
+
+        let Some(token) = self.peek() else {
+            return Err(CoreError::invalid_expression(
+                "an operand is expected at the end".to_string(),
+            ));
+        };
+        self.position += 1;
+
+        match token {
+            Token::UInt(value) => Ok(*value as i128),
+            Token::SInt(value) => Ok(*value as i128),
+            Token::Word(_) if !self.evaluating => Ok(0),
+            Token::Word(name) => self
+                .core
+                .variables
+                .get_integer(name)
+                .ok_or_else(|| CoreError::undefined_variable(name.clone())),
+            Token::Punct("(") => {
+                let value = self.expression()?;
+                self.expect_punct(")")?;
+                Ok(value)
+            }
+            _ => Err(CoreError::invalid_expression(
+                "an operand is expected".to_string(),
+            )),
+        }
+    }
+
+    /// The `size` function evaluates `size(<name>)` that is size of buffer or file with the name.
+    fn size(&mut self) -> CoreResult<i128> {
+        // Skip the function's name.
+        self.position += 1;
+        self.expect_punct("(")?;
+
+        let Some(Token::Word(name)) = self.peek() else {
+            return Err(CoreError::invalid_expression(
+                "a name of buffer or file is expected".to_string(),
+            ));
+        };
+        self.position += 1;
+        self.expect_punct(")")?;
+
+        if !self.evaluating {
+            return Ok(0);
+        }
+
+        let size = match self.core.buffer_size(name) {
+            Some(size) => size,
+            None => self
+                .core
+                .variables
+                .get_file(name)
+                .ok_or_else(|| CoreError::undefined_variable(name.clone()))?
+                .len()?,
+        };
+
+        Ok(size as i128)
+    }
+}
+
+fn is_binary_operator(punct: &str) -> bool {
+    BINARY_OPERATORS
+        .iter()
+        .any(|operators| operators.contains(&punct))
+}
+
+/// The `apply_binary_operator` function applies the operator to given operands.
+fn apply_binary_operator(operator: &str, left: i128, right: i128) -> CoreResult<i128> {
+    if matches!(operator, "/" | "%") && right == 0 {
+        return Err(CoreError::division_by_zero());
+    }
+
+    let value = match operator {
+        "+" => left.checked_add(right),
+        "-" => left.checked_sub(right),
+        "*" => left.checked_mul(right),
+        "/" => left.checked_div(right),
+        "%" => left.checked_rem(right),
+        "==" => Some((left == right) as i128),
+        "!=" => Some((left != right) as i128),
+        "<" => Some((left < right) as i128),
+        "<=" => Some((left <= right) as i128),
+        ">" => Some((left > right) as i128),
+        ">=" => Some((left >= right) as i128),
+        "&&" => Some((left != 0 && right != 0) as i128),
+        "||" => Some((left != 0 || right != 0) as i128),
+        _ => unreachable!(),
+    };
+
+    value.ok_or_else(CoreError::arithmetic_overflow)
+}
+
+/// The `make_integer_token` function returns `Token::UInt` for non-negative value and
+/// `Token::SInt` for negative one. If the value doesn't fit the tokens, the function returns
+/// error.
+fn make_integer_token(value: i128) -> CoreResult<Token> {
+    if value >= 0 {
+        usize::try_from(value).map(Token::uinteger)
+    } else {
+        isize::try_from(value).map(Token::sinteger)
+    }
+    .map_err(|_| CoreError::arithmetic_overflow())
+}
+
+/// The `takes_expression` function checks whether the argument at given position of the command
+/// can be an expression: its parameter takes integers or bytes. Arguments of commands that aren't
+/// registered, like user-defined ones, can be expressions at any position.
+fn takes_expression(info: Option<&CommandInfo>, position: usize) -> bool {
+    let Some(info) = info else {
+        return true;
+    };
+
+    info.parameter(position).is_some_and(|parameter| {
+        matches!(
+            parameter.kind,
+            ArgumentKind::UInt
+                | ArgumentKind::Byte
+                | ArgumentKind::Integer
+                | ArgumentKind::Pattern
+                | ArgumentKind::Data
+        )
+    })
+}
+
+/// The `evaluate_tokens` function replaces each expression in the tokens by its value. Names of
+/// integer variables, `size(<name>)` calls and arithmetic on them are expressions. An integer
+/// literal alone is left as it is.
+///
+/// The command's name is never evaluated. Other arguments are evaluated only if their parameters
+/// take integers or bytes, so an integer variable doesn't hide a buffer or a file with the same
+/// name given as a name or a path.
+pub fn evaluate_tokens(core: &Core, tokens: &[Token]) -> CoreResult<Vec<Token>> {
+    let info = match tokens.first() {
+        Some(Token::Word(name)) => find_command(name),
+        _ => None,
+    };
+
+    let mut evaluator = Evaluator::new(core, tokens);
+    let mut result = Vec::with_capacity(tokens.len());
+
+    while let Some(token) = evaluator.peek() {
+        // Each evaluated expression becomes one token, so the count of tokens in the result is the
+        // position of the argument.
+        if evaluator.position == 0
+            || !takes_expression(info, result.len())
+            || !evaluator.starts_expression()
+        {
+            result.push(token.clone());
+            evaluator.position += 1;
+            continue;
+        }
+
+        let start = evaluator.position;
+        let value = evaluator.expression()?;
+
+        if evaluator.position - start == 1 && matches!(token, Token::UInt(_) | Token::SInt(_)) {
+            result.push(token.clone());
+        } else {
+            result.push(make_integer_token(value)?);
+        }
+    }
+
+    Ok(result)
+}
+
+/// The `evaluate_expression` function evaluates the tokens as one expression. If there're tokens
+/// left after the expression, the function returns error.
+pub fn evaluate_expression(core: &Core, tokens: &[Token]) -> CoreResult<i128> {
+    let mut evaluator = Evaluator::new(core, tokens);
+    let value = evaluator.expression()?;
+
+    if evaluator.position < tokens.len() {
+        return Err(CoreError::invalid_expression(
+            "the end of expression is expected".to_string(),
+        ));
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::command::CoreCommand;
+
+    fn core_with_integer(name: &str, value: i128) -> Core {
+        let mut core = Core::new();
+        core.execute(CoreCommand::Let {
+            name: name.to_string(),
+            value,
+        })
+        .unwrap();
+        core
+    }
+
+    fn word(word: &str) -> Token {
+        Token::word(word.to_string())
+    }
+
+    #[test]
+    fn names_are_not_evaluated() {
+        let core = core_with_integer("n", 5);
+
+        let tokens = vec![word("make_buffer"), word("n"), Token::uinteger(10)];
+        assert_eq!(evaluate_tokens(&core, &tokens).unwrap(), tokens);
+
+        let tokens = vec![word("open_file"), word("n"), word("as"), word("n")];
+        assert_eq!(evaluate_tokens(&core, &tokens).unwrap(), tokens);
+    }
+
+    #[test]
+    fn integer_arguments_are_evaluated() {
+        let core = core_with_integer("n", 5);
+        let tokens = vec![
+            word("fill_buffer"),
+            word("n"),
+            word("n"),
+            Token::uinteger(0),
+            word("n"),
+            Token::punct("+"),
+            Token::uinteger(1),
+        ];
+
+        assert_eq!(
+            evaluate_tokens(&core, &tokens).unwrap(),
+            vec![
+                word("fill_buffer"),
+                word("n"),
+                Token::uinteger(5),
+                Token::uinteger(0),
+                Token::uinteger(6),
+            ]
+        );
+    }
+
+    #[test]
+    fn arguments_of_unknown_commands_are_evaluated() {
+        let core = core_with_integer("n", 5);
+        let tokens = vec![word("write_entry"), word("n"), word("mbr")];
+
+        assert_eq!(
+            evaluate_tokens(&core, &tokens).unwrap(),
+            vec![word("write_entry"), Token::uinteger(5), word("mbr")]
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use std::fs::File as RawFile;
-use std::fs::OpenOptions;
+use std::fs::{self, OpenOptions, Permissions};
 use std::io::{Read, Result as IoResult, Seek, SeekFrom, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// The `read` function writes bytes from the file in first argument starting from index in second
 /// argument to buffer in third argument. It means that if you call `read(file, 3, &mut write_to)`,
@@ -26,15 +26,54 @@ fn write(raw: &mut RawFile, start: usize, write_from: &[u8]) -> IoResult<usize>
     raw.write(write_from)
 }
 
+/// The `TEMP_EXTENSION` is added to the name of temporary file used for atomic replacing.
+const TEMP_EXTENSION: &str = ".fmod-tmp";
+
+/// The `write_temp` function writes given bytes to the file with given path and flushes them to
+/// the disk. If there's permissions, they're set to the file.
+fn write_temp(temp_path: &Path, bytes: &[u8], permissions: Option<Permissions>) -> IoResult<()> {
+    let mut temp = RawFile::create(temp_path)?;
+
+    temp.write_all(bytes)?;
+    if let Some(permissions) = permissions {
+        temp.set_permissions(permissions)?;
+    }
+    temp.sync_all()
+}
+
+/// The `replace_atomically` function replaces content of the file with given path by given bytes.
+/// The bytes are written to a temporary file in the same directory which is synced to the disk
+/// and renamed then, so the file is never left half-written. The file keeps its permissions. If
+/// the file doesn't exist, it's created.
+pub fn replace_atomically<T: AsRef<Path>>(path: T, bytes: &[u8]) -> IoResult<()> {
+    let path = path.as_ref();
+    let permissions = fs::metadata(path).ok().map(|meta| meta.permissions());
+
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(TEMP_EXTENSION);
+    let temp_path = PathBuf::from(temp_path);
+
+    let result =
+        write_temp(&temp_path, bytes, permissions).and_then(|_| fs::rename(&temp_path, path));
+
+    if result.is_err() {
+        // The temporary file may not exist, so the error is ignored.
+        let _ = fs::remove_file(&temp_path);
+    }
+
+    result
+}
+
 /// The `NewFile` structure lets do operations for writing and reading. Also, making `NewFile`
 /// you create new file in directory.
 pub struct NewFile {
     // INVARIANTS:
     // - `NewFile::raw` is always opened in write-read mode.
-    // - `NewFile::raw` is new file in directory.
+    // - `NewFile::raw` is new file in directory or existing file that was truncated.
 
-    // This file is new, created with write and create_new flags.
+    // This file is new, created with write and create_new (or create and truncate) flags.
     raw: RawFile,
+    path: PathBuf,
 }
 
 impl NewFile {
@@ -47,11 +86,47 @@ impl NewFile {
             .write(true)
             .read(true)
             .create_new(true)
-            .open(path)?;
+            .open(&path)?;
 
         raw_file.write_all(&vec![0; size])?;
 
-        Ok(NewFile { raw: raw_file })
+        Ok(NewFile {
+            raw: raw_file,
+            path: path.as_ref().to_path_buf(),
+        })
+    }
+
+    /// The `overwrite` function does the same as `NewFile::new` but if file with the given path
+    /// already exists, it's truncated instead of returning `Err`.
+    pub fn overwrite<T: AsRef<Path>>(path: T, size: usize) -> IoResult<Self> {
+        let mut raw_file = OpenOptions::new()
+            .write(true)
+            .read(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)?;
+
+        raw_file.write_all(&vec![0; size])?;
+
+        Ok(NewFile {
+            raw: raw_file,
+            path: path.as_ref().to_path_buf(),
+        })
+    }
+
+    /// The `create` function calls `NewFile::overwrite` if `overwrite` is set and `NewFile::new`
+    /// otherwise.
+    pub fn create<T: AsRef<Path>>(path: T, size: usize, overwrite: bool) -> IoResult<Self> {
+        if overwrite {
+            NewFile::overwrite(path, size)
+        } else {
+            NewFile::new(path, size)
+        }
+    }
+
+    /// The `path` function returns the path the file was opened or created with.
+    pub fn path(&self) -> &Path {
+        &self.path
     }
 
     /// The `len` function returns length of the file.
@@ -64,6 +139,7 @@ impl NewFile {
     /// The `write_byte` function writes byte in first argument into index in second argument.
     ///
     /// If the index goes beyound the bounder, the function panics.
+    #[allow(dead_code)]
     pub fn write_byte(&mut self, byte: u8, index: usize) -> IoResult<()> {
         let buffer = std::array::from_ref(&byte);
 
@@ -91,6 +167,7 @@ impl NewFile {
 
     /// The `read_byte` returns a byte from a given index. If index is wrong anyway, the function
     /// causes panic.
+    #[allow(dead_code)]
     pub fn read_byte(&mut self, index: usize) -> IoResult<u8> {
         let mut buf = 0_u8;
 
@@ -126,6 +203,7 @@ impl NewFile {
 pub struct ReadFile {
     // This file is opened with read flag only.
     raw: RawFile,
+    path: PathBuf,
 }
 
 impl ReadFile {
@@ -133,10 +211,16 @@ impl ReadFile {
     /// and creates this structures.
     pub fn new<T: AsRef<Path>>(path: T) -> IoResult<Self> {
         Ok(ReadFile {
-            raw: OpenOptions::new().read(true).open(path)?,
+            raw: OpenOptions::new().read(true).open(&path)?,
+            path: path.as_ref().to_path_buf(),
         })
     }
 
+    /// The `path` function returns the path the file was opened or created with.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
     /// The `len` function returns length of the file.
     pub fn len(&self) -> IoResult<usize> {
         self.raw.metadata().map(|meta| meta.len() as usize)
@@ -146,6 +230,7 @@ impl ReadFile {
 
     /// The `read_byte` returns a byte from a given index. If index is wrong anyway, the function
     /// causes panic.
+    #[allow(dead_code)]
     pub fn read_byte(&mut self, index: usize) -> IoResult<u8> {
         let mut buf = 0_u8;
 
@@ -176,10 +261,111 @@ impl ReadFile {
     // }
 }
 
-/// The `File` enumeration contains two variants:
+/// The `WriteFile` structure lets do operations for writing and reading. This structure opens
+/// existing file in read-write mode; the file isn't truncated.
+pub struct WriteFile {
+    // This file is opened with read and write flags.
+    raw: RawFile,
+    path: PathBuf,
+}
+
+impl WriteFile {
+    /// The `new` function opens file in directory in read-write mode (if it doesn't exist, the
+    /// function returns `Err`) and creates this structure.
+    pub fn new<T: AsRef<Path>>(path: T) -> IoResult<Self> {
+        Ok(WriteFile {
+            raw: OpenOptions::new().read(true).write(true).open(&path)?,
+            path: path.as_ref().to_path_buf(),
+        })
+    }
+
+    /// The `path` function returns the path the file was opened or created with.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The `len` function returns length of the file.
+    pub fn len(&self) -> IoResult<usize> {
+        self.raw.metadata().map(|meta| meta.len() as usize)
+    }
+
+    // Write {
+
+    /// The `write_bytes` function writes bytes from bytes in first argument starting with index in
+    /// second argument. The function returns count of written bytes.
+    ///
+    /// If second argument goes beyound the bounder, the file is extended.
+    pub fn write_bytes(&mut self, bytes: &[u8], start_with: usize) -> IoResult<usize> {
+        write(&mut self.raw, start_with, bytes)
+    }
+
+    // }
+
+    // Read {
+
+    /// The `read_bytes` returns a slice of file's bytes starting from the first argument and
+    /// ending by the second argument. If `start` goes beyond the boundaries, the function returns
+    /// `None`.
+    pub fn read_bytes(&mut self, start: usize, end: usize) -> IoResult<Option<Box<[u8]>>> {
+        if start >= self.len()? {
+            return Ok(None);
+        }
+
+        let mut buffer = Box::from_iter((start..end.min(self.len()?)).map(|_| 0_u8));
+
+        read(&mut self.raw, start, &mut buffer[..])?;
+
+        Ok(Some(buffer))
+    }
+
+    // }
+}
+
+/// The `File` enumeration contains three variants:
 /// - New file for writing.
 /// - Opened existing file for reading.
+/// - Opened existing file for reading and writing.
 pub enum File {
     New(NewFile),
     ToRead(ReadFile),
+    ToWrite(WriteFile),
+}
+
+impl File {
+    /// The `len` function returns length of the file.
+    pub fn len(&self) -> IoResult<usize> {
+        match self {
+            File::New(f) => f.len(),
+            File::ToRead(f) => f.len(),
+            File::ToWrite(f) => f.len(),
+        }
+    }
+
+    /// The `path` function returns the path the file was opened or created with.
+    pub fn path(&self) -> &Path {
+        match self {
+            File::New(f) => f.path(),
+            File::ToRead(f) => f.path(),
+            File::ToWrite(f) => f.path(),
+        }
+    }
+
+    /// The `mode` function returns how the file is opened: `read` or `read-write`.
+    pub fn mode(&self) -> &'static str {
+        match self {
+            File::ToRead(_) => "read",
+            File::New(_) | File::ToWrite(_) => "read-write",
+        }
+    }
+
+    /// The `read_bytes` returns a slice of file's bytes starting from the first argument and
+    /// ending by the second argument. If `start` goes beyond the boundaries, the function returns
+    /// `None`.
+    pub fn read_bytes(&mut self, start: usize, end: usize) -> IoResult<Option<Box<[u8]>>> {
+        match self {
+            File::New(f) => f.read_bytes(start, end),
+            File::ToRead(f) => f.read_bytes(start, end),
+            File::ToWrite(f) => f.read_bytes(start, end),
+        }
+    }
 }
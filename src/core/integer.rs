@@ -0,0 +1,159 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The `IntType` enumeration contains all types of integers that can be read from and written to
+/// buffers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntType {
+    U8,
+    U16,
+    U32,
+    U64,
+    I16,
+    I32,
+    I64,
+}
+
+impl IntType {
+    /// The `from_name` function returns the type with given name: `u8`, `u16`, `u32`, `u64`,
+    /// `i16`, `i32` or `i64`. If there's no type with the name, the function returns `None`.
+    pub fn from_name(name: &str) -> Option<IntType> {
+        match name {
+            "u8" => Some(IntType::U8),
+            "u16" => Some(IntType::U16),
+            "u32" => Some(IntType::U32),
+            "u64" => Some(IntType::U64),
+            "i16" => Some(IntType::I16),
+            "i32" => Some(IntType::I32),
+            "i64" => Some(IntType::I64),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            IntType::U8 => "u8",
+            IntType::U16 => "u16",
+            IntType::U32 => "u32",
+            IntType::U64 => "u64",
+            IntType::I16 => "i16",
+            IntType::I32 => "i32",
+            IntType::I64 => "i64",
+        }
+    }
+
+    /// The `width` function returns count of bytes the integer takes.
+    pub fn width(&self) -> usize {
+        match self {
+            IntType::U8 => 1,
+            IntType::U16 | IntType::I16 => 2,
+            IntType::U32 | IntType::I32 => 4,
+            IntType::U64 | IntType::I64 => 8,
+        }
+    }
+
+    pub fn is_signed(&self) -> bool {
+        matches!(self, IntType::I16 | IntType::I32 | IntType::I64)
+    }
+
+    /// The `min` function returns the least value of the type.
+    pub fn min(&self) -> i128 {
+        if self.is_signed() {
+            -(1 << (self.width() * 8 - 1))
+        } else {
+            0
+        }
+    }
+
+    /// The `max` function returns the greatest value of the type.
+    pub fn max(&self) -> i128 {
+        if self.is_signed() {
+            (1 << (self.width() * 8 - 1)) - 1
+        } else {
+            (1 << (self.width() * 8)) - 1
+        }
+    }
+
+    /// The `fits` function checks whether the value can be stored in the type.
+    pub fn fits(&self, value: i128) -> bool {
+        (self.min()..=self.max()).contains(&value)
+    }
+}
+
+impl Display for IntType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The `Endianness` enumeration sets the order of bytes in multi-byte values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endianness {
+    Little,
+    Big,
+}
+
+impl Endianness {
+    /// The `from_name` function returns the endianness with given name: `le` or `be`. If there's
+    /// no endianness with the name, the function returns `None`.
+    pub fn from_name(name: &str) -> Option<Endianness> {
+        match name {
+            "le" => Some(Endianness::Little),
+            "be" => Some(Endianness::Big),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Endianness::Little => "le",
+            Endianness::Big => "be",
+        }
+    }
+}
+
+impl Display for Endianness {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The `encode_int` function turns the value into bytes of given type in given order. Negative
+/// values are written in two's complement. If the value doesn't fit the type, the function returns
+/// `None`.
+pub fn encode_int(value: i128, int_type: IntType, endianness: Endianness) -> Option<Vec<u8>> {
+    if !int_type.fits(value) {
+        return None;
+    }
+
+    let mut bytes = value.to_le_bytes()[..int_type.width()].to_vec();
+
+    if endianness == Endianness::Big {
+        bytes.reverse();
+    }
+
+    Some(bytes)
+}
+
+/// The `decode_int` function turns bytes of given type in given order into the value.
+///
+/// Accepted guarantees:
+/// - `bytes.len() == int_type.width()`.
+pub fn decode_int(bytes: &[u8], int_type: IntType, endianness: Endianness) -> i128 {
+    debug_assert_eq!(bytes.len(), int_type.width());
+
+    let mut le_bytes = [0_u8; 16];
+    le_bytes[..bytes.len()].copy_from_slice(bytes);
+
+    if endianness == Endianness::Big {
+        le_bytes[..bytes.len()].reverse();
+    }
+
+    let value = u128::from_le_bytes(le_bytes) as i128;
+
+    if int_type.is_signed() && value > int_type.max() {
+        // The sign bit is set, so the value is negative.
+        value - (1 << (int_type.width() * 8))
+    } else {
+        value
+    }
+}
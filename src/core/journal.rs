@@ -0,0 +1,655 @@
+use super::buffer::Buffer;
+use super::command::CoreCommand;
+use super::variables::{VariableValue, Variables};
+
+use std::collections::VecDeque;
+
+/// The `Change` enumeration describes one reversible change of the variables.
+///
+/// Each change keeps the "other" state of the piece it's related to. Applying a change swaps that
+/// state with the current one, so applying the same change twice gives the initial state back.
+/// Thanks to this, one change serves both for undo and for redo.
+enum Change {
+    /// Bytes of buffer `buffer_name` starting from `start`.
+    Bytes {
+        buffer_name: String,
+        start: usize,
+        bytes: Box<[u8]>,
+    },
+    /// Bytes of buffer `buffer_name` starting from `start` that replace `count` bytes there. The
+    /// count of bytes may differ, so the buffer is resized.
+    Splice {
+        buffer_name: String,
+        start: usize,
+        bytes: Vec<u8>,
+        count: usize,
+    },
+    /// Whole value bound to `name`. `None` means there's no variable with the name.
+    Binding {
+        name: String,
+        value: Option<VariableValue>,
+    },
+    /// The value bound to `from` that is bound to `to` on applying. After that, the names are
+    /// swapped.
+    Move { from: String, to: String },
+}
+
+impl Change {
+    /// The `apply` function swaps the kept state with the current state of the variables.
+    fn apply(&mut self, variables: &mut Variables) {
+        match self {
+            Change::Bytes {
+                buffer_name,
+                start,
+                bytes,
+            } => {
+                // The journal is consistent with the variables, so the buffer exists.
+                if let Some(buffer) = variables.get_buffer_mut(buffer_name) {
+                    buffer.swap_bytes(bytes, *start);
+                }
+            }
+            Change::Splice {
+                buffer_name,
+                start,
+                bytes,
+                count,
+            } => {
+                // The journal is consistent with the variables, so the buffer and the range exist.
+                if let Some(buffer) = variables.get_buffer_mut(buffer_name) {
+                    if let Some(replaced) = buffer.splice_bytes(*start, *start + *count, bytes) {
+                        *count = bytes.len();
+                        *bytes = replaced;
+                    }
+                }
+            }
+            Change::Binding { name, value } => {
+                *value = variables.replace(name.clone(), value.take());
+            }
+            Change::Move { from, to } => {
+                let value = variables.replace(from.clone(), None);
+                variables.replace(to.clone(), value);
+                std::mem::swap(from, to);
+            }
+        }
+    }
+}
+
+/// The `Step` structure is a set of changes made by one command.
+struct Step {
+    description: String,
+    changes: Vec<Change>,
+}
+
+impl Step {
+    fn undo(&mut self, variables: &mut Variables) {
+        self.changes
+            .iter_mut()
+            .rev()
+            .for_each(|change| change.apply(variables));
+    }
+
+    fn redo(&mut self, variables: &mut Variables) {
+        self.changes
+            .iter_mut()
+            .for_each(|change| change.apply(variables));
+    }
+}
+
+/// The `Snapshot` structure keeps the state of the variables the command is going to change. It's
+/// taken before the command's executing and turned into a step after that.
+///
+/// Values aren't copied for bindings: the snapshot remembers names only, and values the command
+/// releases are moved to it by the `finish` function.
+pub struct Snapshot {
+    description: String,
+    changes: Vec<Change>,
+    /// Names of the bindings that have a value before the command.
+    bound_names: Vec<String>,
+}
+
+impl Snapshot {
+    /// The `take` function saves the state of the variables the given command is going to change.
+    /// If the command doesn't change anything, the snapshot is empty.
+    pub fn take(variables: &Variables, command: &CoreCommand) -> Snapshot {
+        let mut changes = Vec::new();
+        let mut bound_names = Vec::new();
+
+        match *command {
+            CoreCommand::FillBuffer {
+                buffer_name,
+                start,
+                end,
+                ..
+            } => push_bytes(&mut changes, variables, buffer_name, start, end),
+            CoreCommand::BufferSetByte {
+                buffer_name, index, ..
+            } => push_bytes(
+                &mut changes,
+                variables,
+                buffer_name,
+                index,
+                index.saturating_add(1),
+            ),
+            CoreCommand::BufferWriteBytes {
+                buffer_name,
+                start,
+                ref bytes,
+            } => push_bytes(
+                &mut changes,
+                variables,
+                buffer_name,
+                start,
+                start.saturating_add(bytes.len()),
+            ),
+            CoreCommand::BufferWriteInt {
+                buffer_name,
+                index,
+                int_type,
+                ..
+            } => push_bytes(
+                &mut changes,
+                variables,
+                buffer_name,
+                index,
+                index.saturating_add(int_type.width()),
+            ),
+            CoreCommand::BufferWriteStr {
+                buffer_name,
+                index,
+                text,
+                encoding,
+                nul,
+            } => push_bytes(
+                &mut changes,
+                variables,
+                buffer_name,
+                index,
+                index.saturating_add(encoding.encode(text, nul).map_or(0, |bytes| bytes.len())),
+            ),
+            CoreCommand::ChecksumWrite {
+                algorithm,
+                buffer_name,
+                index,
+                layout,
+                ..
+            } => push_bytes(
+                &mut changes,
+                variables,
+                buffer_name,
+                index,
+                index.saturating_add(
+                    layout.map_or(algorithm.width(), |(int_type, _)| int_type.width()),
+                ),
+            ),
+            CoreCommand::BufferResize {
+                buffer_name,
+                new_size,
+            } => {
+                if let Some(size) = variables.get_buffer(buffer_name).map(Buffer::len) {
+                    push_splice(
+                        &mut changes,
+                        variables,
+                        buffer_name,
+                        (new_size.min(size), size),
+                        new_size.saturating_sub(size),
+                    )
+                }
+            }
+            CoreCommand::BufferInsert {
+                buffer_name,
+                offset,
+                ref bytes,
+            } => push_splice(
+                &mut changes,
+                variables,
+                buffer_name,
+                (offset, offset),
+                bytes.len(),
+            ),
+            CoreCommand::BufferDelete {
+                buffer_name,
+                start,
+                end,
+            } => push_splice(&mut changes, variables, buffer_name, (start, end), 0),
+            CoreCommand::BufferAppend {
+                buffer_name,
+                ref bytes,
+            } => {
+                if let Some(size) = variables.get_buffer(buffer_name).map(Buffer::len) {
+                    push_splice(
+                        &mut changes,
+                        variables,
+                        buffer_name,
+                        (size, size),
+                        bytes.len(),
+                    )
+                }
+            }
+            CoreCommand::FromFileToBuffer {
+                buffer_name,
+                bytes_count,
+                buffer_start,
+                ..
+            } => push_bytes(
+                &mut changes,
+                variables,
+                buffer_name,
+                buffer_start,
+                buffer_start.saturating_add(bytes_count),
+            ),
+            CoreCommand::MakeBuffer {
+                buffer_name: ref name,
+                ..
+            }
+            | CoreCommand::CreateFile {
+                file_name: ref name,
+                ..
+            }
+            | CoreCommand::OpenFile {
+                file_name: ref name,
+                ..
+            }
+            | CoreCommand::OpenFileRw {
+                file_name: ref name,
+                ..
+            }
+            | CoreCommand::MergeBuffers {
+                new_buffer_name: ref name,
+                ..
+            }
+            | CoreCommand::PullOutSlice {
+                new_buffer_name: ref name,
+                ..
+            }
+            | CoreCommand::TurnBufferToFile {
+                new_file_name: ref name,
+                ..
+            }
+            | CoreCommand::TurnFileToBuffer {
+                new_buffer_name: ref name,
+                ..
+            }
+            | CoreCommand::CopyBuffer {
+                new_buffer_name: ref name,
+                ..
+            }
+            | CoreCommand::Let { ref name, .. } => {
+                push_binding(&mut changes, &mut bound_names, variables, name)
+            }
+            CoreCommand::Drop { name } => {
+                push_binding(&mut changes, &mut bound_names, variables, name)
+            }
+            CoreCommand::Rename { old_name, new_name } if old_name != new_name => {
+                push_binding(&mut changes, &mut bound_names, variables, new_name);
+                changes.push(Change::Move {
+                    from: new_name.to_string(),
+                    to: old_name.to_string(),
+                });
+            }
+            _ => {}
+        }
+
+        Snapshot {
+            description: command.to_string(),
+            changes,
+            bound_names,
+        }
+    }
+
+    /// The `finish` function moves values released by the command to the bindings with their
+    /// names. A binding that had a value and wasn't released isn't changed, so it's left out.
+    pub fn finish(mut self, mut released: Vec<(String, VariableValue)>) -> Snapshot {
+        let bound_names = &self.bound_names;
+
+        self.changes.retain_mut(|change| match change {
+            Change::Binding { name, value } => {
+                if let Some(index) = released
+                    .iter()
+                    .position(|(released_name, _)| released_name == name)
+                {
+                    *value = Some(released.swap_remove(index).1);
+                }
+                value.is_some() || !bound_names.contains(name)
+            }
+            _ => true,
+        });
+
+        self
+    }
+
+    /// The `is_empty` function returns `true` if the command the snapshot was taken for doesn't
+    /// change anything.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// The `push_binding` function pushes the binding with the given name to the changes. Its value
+/// is moved there when the snapshot is finished.
+fn push_binding(
+    changes: &mut Vec<Change>,
+    bound_names: &mut Vec<String>,
+    variables: &Variables,
+    name: &str,
+) {
+    if variables.get(name).is_some() {
+        bound_names.push(name.to_string());
+    }
+
+    changes.push(Change::Binding {
+        name: name.to_string(),
+        value: None,
+    });
+}
+
+/// The `push_bytes` function pushes bytes of the buffer with the given name from `start` to `end`
+/// to the changes. If there's no such buffer or no such bytes, the function does nothing: the
+/// command will fail or won't change anything.
+fn push_bytes(
+    changes: &mut Vec<Change>,
+    variables: &Variables,
+    buffer_name: &str,
+    start: usize,
+    end: usize,
+) {
+    let Some(buffer) = variables.get_buffer(buffer_name) else { return };
+    let Some(bytes) = buffer.read_bytes(start, end) else { return };
+
+    if bytes.is_empty() {
+        return;
+    }
+
+    changes.push(Change::Bytes {
+        buffer_name: buffer_name.to_string(),
+        start,
+        bytes: Box::from(bytes),
+    });
+}
+
+/// The `push_splice` function pushes bytes of the buffer with the given name in the range which
+/// is going to be replaced by `count` bytes. If there's no such buffer or no such range, or
+/// nothing is replaced, the function does nothing: the command will fail or won't change
+/// anything.
+fn push_splice(
+    changes: &mut Vec<Change>,
+    variables: &Variables,
+    buffer_name: &str,
+    (start, end): (usize, usize),
+    count: usize,
+) {
+    let Some(buffer) = variables.get_buffer(buffer_name) else { return };
+
+    if start > end || end > buffer.len() || (start == end && count == 0) {
+        return;
+    }
+
+    changes.push(Change::Splice {
+        buffer_name: buffer_name.to_string(),
+        start,
+        bytes: buffer.read_bytes(start, end).unwrap_or_default().to_vec(),
+        count,
+    });
+}
+
+/// The `Journal` structure records changes of the variables made by commands and lets undo and
+/// redo them.
+///
+/// The journal keeps no more than `depth` steps. When a new step is recorded over the limit, the
+/// oldest one is forgotten. When a new step is recorded, all undone steps are forgotten.
+///
+/// Only variables are journaled: contents of files on the disk are not restored.
+pub struct Journal {
+    // Steps that can be undone. The last one is the newest.
+    done: VecDeque<Step>,
+    // Steps that can be redone. The last one is the most recently undone.
+    undone: Vec<Step>,
+    depth: usize,
+}
+
+impl Journal {
+    /// The `new` function creates empty journal that keeps no more than `depth` steps.
+    pub fn new(depth: usize) -> Self {
+        Journal {
+            done: VecDeque::new(),
+            undone: Vec::new(),
+            depth,
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The `set_depth` function changes the limit of steps. If there're more steps than the new
+    /// limit, the oldest done steps and the farthest undone ones are forgotten.
+    pub fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+
+        while self.done.len() > depth {
+            self.done.pop_front();
+        }
+        // The last undone steps are the nearest to the current state, so the first ones are
+        // forgotten.
+        let excess = self.undone.len().saturating_sub(depth);
+        self.undone.drain(..excess);
+    }
+
+    /// The `record` function turns the given snapshot into a step. The snapshot must be taken
+    /// before the successful executing of the command.
+    pub fn record(&mut self, snapshot: Snapshot) {
+        if snapshot.is_empty() || self.depth == 0 {
+            return;
+        }
+
+        self.undone.clear();
+        self.done.push_back(Step {
+            description: snapshot.description,
+            changes: snapshot.changes,
+        });
+
+        if self.done.len() > self.depth {
+            self.done.pop_front();
+        }
+    }
+
+    /// The `undo` function undoes the newest step and returns its description. If there's nothing
+    /// to undo, the function returns `None`.
+    pub fn undo(&mut self, variables: &mut Variables) -> Option<String> {
+        let mut step = self.done.pop_back()?;
+        step.undo(variables);
+
+        let description = step.description.clone();
+        self.undone.push(step);
+
+        Some(description)
+    }
+
+    /// The `redo` function redoes the most recently undone step and returns its description. If
+    /// there's nothing to redo, the function returns `None`.
+    pub fn redo(&mut self, variables: &mut Variables) -> Option<String> {
+        let mut step = self.undone.pop()?;
+        step.redo(variables);
+
+        let description = step.description.clone();
+        self.done.push_back(step);
+
+        Some(description)
+    }
+
+    /// The `done` function returns descriptions of steps that can be undone from the oldest to the
+    /// newest.
+    pub fn done(&self) -> impl Iterator<Item = &str> {
+        self.done.iter().map(|step| step.description.as_str())
+    }
+
+    /// The `undone` function returns descriptions of steps that can be redone in order they were
+    /// recorded.
+    pub fn undone(&self) -> impl Iterator<Item = &str> {
+        self.undone
+            .iter()
+            .rev()
+            .map(|step| step.description.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variables(buffer_bytes: &[u8]) -> Variables {
+        let mut buffer = Buffer::new(buffer_bytes.len());
+        buffer.write_bytes(buffer_bytes, 0);
+
+        let mut variables = Variables::new();
+        variables.new_buffer("b".to_string(), buffer);
+        variables
+    }
+
+    fn bytes(variables: &Variables) -> &[u8] {
+        let buffer = variables.get_buffer("b").unwrap();
+        buffer.read_bytes(0, buffer.len()).unwrap()
+    }
+
+    fn splice(start: usize, bytes: &[u8], count: usize) -> Change {
+        Change::Splice {
+            buffer_name: "b".to_string(),
+            start,
+            bytes: bytes.to_vec(),
+            count,
+        }
+    }
+
+    #[test]
+    fn splice_undoes_insertion() {
+        // The buffer after inserting 7 and 8 at 1.
+        let mut variables = variables(&[1, 7, 8, 2]);
+        let mut change = splice(1, &[], 2);
+
+        change.apply(&mut variables);
+        assert_eq!(bytes(&variables), [1, 2]);
+
+        change.apply(&mut variables);
+        assert_eq!(bytes(&variables), [1, 7, 8, 2]);
+    }
+
+    #[test]
+    fn splice_undoes_deletion() {
+        // The buffer after deleting 2 and 3 from [1, 2, 3, 4].
+        let mut variables = variables(&[1, 4]);
+        let mut change = splice(1, &[2, 3], 0);
+
+        change.apply(&mut variables);
+        assert_eq!(bytes(&variables), [1, 2, 3, 4]);
+
+        change.apply(&mut variables);
+        assert_eq!(bytes(&variables), [1, 4]);
+    }
+
+    #[test]
+    fn splice_undoes_resizing() {
+        // The buffer after resizing [1, 2, 3] to 1 byte and then to 4 bytes.
+        let mut variables = variables(&[1, 0, 0, 0]);
+        let mut growth = splice(1, &[], 3);
+        let mut shrinkage = splice(1, &[2, 3], 0);
+
+        growth.apply(&mut variables);
+        assert_eq!(bytes(&variables), [1]);
+        shrinkage.apply(&mut variables);
+        assert_eq!(bytes(&variables), [1, 2, 3]);
+
+        shrinkage.apply(&mut variables);
+        growth.apply(&mut variables);
+        assert_eq!(bytes(&variables), [1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn snapshot_of_splice() {
+        let variables = variables(&[1, 2, 3, 4]);
+
+        let delete = CoreCommand::BufferDelete {
+            buffer_name: "b",
+            start: 1,
+            end: 3,
+        };
+        let snapshot = Snapshot::take(&variables, &delete);
+        assert!(matches!(
+            snapshot.changes[..],
+            [Change::Splice { start: 1, ref bytes, count: 0, .. }] if bytes == &[2, 3]
+        ));
+
+        // Wrong ranges and empty insertions don't change anything.
+        for command in [
+            CoreCommand::BufferDelete {
+                buffer_name: "b",
+                start: 3,
+                end: 1,
+            },
+            CoreCommand::BufferDelete {
+                buffer_name: "b",
+                start: 2,
+                end: 5,
+            },
+            CoreCommand::BufferInsert {
+                buffer_name: "b",
+                offset: 5,
+                bytes: vec![7],
+            },
+            CoreCommand::BufferInsert {
+                buffer_name: "b",
+                offset: 2,
+                bytes: vec![],
+            },
+        ] {
+            assert!(Snapshot::take(&variables, &command).is_empty());
+        }
+    }
+
+    #[test]
+    fn depth_keeps_nearest_undone_steps() {
+        let mut variables = variables(&[0, 0, 0, 0]);
+        let mut journal = Journal::new(10);
+
+        for index in 0..3 {
+            let command = CoreCommand::BufferSetByte {
+                buffer_name: "b",
+                index,
+                value: index as u8 + 1,
+            };
+            let snapshot = Snapshot::take(&variables, &command);
+            variables
+                .get_buffer_mut("b")
+                .unwrap()
+                .write_byte(index as u8 + 1, index);
+            journal.record(snapshot.finish(variables.take_released()));
+        }
+        for _ in 0..3 {
+            journal.undo(&mut variables).unwrap();
+        }
+
+        journal.set_depth(1);
+        assert_eq!(
+            journal.undone().collect::<Vec<_>>(),
+            ["buffer_set_byte b 0 1"]
+        );
+
+        journal.redo(&mut variables).unwrap();
+        assert_eq!(bytes(&variables), [1, 0, 0, 0]);
+        assert!(journal.redo(&mut variables).is_none());
+    }
+
+    #[test]
+    fn dropped_value_is_moved() {
+        let mut variables = variables(&[1, 2]);
+        let drop = CoreCommand::Drop { name: "b" };
+
+        let snapshot = Snapshot::take(&variables, &drop);
+        variables.unbind("b");
+        let mut snapshot = snapshot.finish(variables.take_released());
+
+        snapshot.changes[0].apply(&mut variables);
+        assert_eq!(bytes(&variables), [1, 2]);
+
+        snapshot.changes[0].apply(&mut variables);
+        assert!(variables.get("b").is_none());
+    }
+}
@@ -1,17 +1,32 @@
+pub mod checksum;
 pub mod command;
+#[allow(clippy::module_inception)]
 pub mod core;
 pub mod core_e;
+pub mod encoding;
+pub mod integer;
 pub mod output;
+pub mod registry;
+pub mod settings;
 pub mod token;
 pub mod token_parser;
+pub mod view;
 
 mod buffer;
 mod commands;
+mod expression;
 mod file;
+mod journal;
 mod variables;
 
+pub use checksum::*;
 pub use command::*;
 pub use core::*;
 pub use core_e::*;
+pub use encoding::*;
+pub use integer::*;
+pub use registry::*;
+pub use settings::*;
 pub use token::*;
 pub use token_parser::*;
+pub use view::*;
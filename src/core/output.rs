@@ -83,10 +83,114 @@ impl Table {
     }
 }
 
-pub enum OtherInfo {
-    Table2Column { data: Vec<(String, String)> },
+/// The `DiffRange` structure is a range of bytes where two sequences differ. The range may contain
+/// equal bytes between differing ones.
+pub struct DiffRange {
+    start: usize,
+
+    // There's invariant: `left.len == right.len`.
+    //
+    // `None` means the sequence has ended before the byte.
+    left: Vec<Option<u8>>,
+    right: Vec<Option<u8>>,
+}
+
+impl DiffRange {
+    pub fn new(start: usize, left: Vec<Option<u8>>, right: Vec<Option<u8>>) -> Self {
+        debug_assert_eq!(left.len(), right.len());
+
+        DiffRange { start, left, right }
+    }
+
+    /// The `start` function returns index the range starts with.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty()
+    }
+
+    /// The `left` function returns bytes of the first sequence in the range.
+    pub fn left(&self) -> &[Option<u8>] {
+        &self.left
+    }
+
+    /// The `right` function returns bytes of the second sequence in the range.
+    pub fn right(&self) -> &[Option<u8>] {
+        &self.right
+    }
+}
+
+/// The `ByteClass` enumeration is a kind of bytes hex dumps are colored by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteClass {
+    Zero,
+    /// Printable ASCII characters.
+    Printable,
+    /// ASCII control characters except zero.
+    Control,
+    /// The byte `0xFF`.
+    Full,
+    /// Bytes from `0x80` to `0xFE`.
+    Other,
+}
+
+impl ByteClass {
+    pub fn of(byte: u8) -> ByteClass {
+        match byte {
+            0x00 => ByteClass::Zero,
+            0x20..=0x7E => ByteClass::Printable,
+            0x01..=0x1F | 0x7F => ByteClass::Control,
+            0xFF => ByteClass::Full,
+            _ => ByteClass::Other,
+        }
+    }
+
+    /// The `of_bytes` function returns class shared by all given bytes. If they're of different
+    /// classes or there're no bytes, the function returns `None`.
+    pub fn of_bytes(bytes: &[u8]) -> Option<ByteClass> {
+        let class = ByteClass::of(*bytes.first()?);
+        bytes
+            .iter()
+            .all(|byte| ByteClass::of(*byte) == class)
+            .then_some(class)
+    }
+}
+
+/// The `TableColors` structure keeps classes of bytes a big table is colored by. `None` means the
+/// cell or the character isn't colored.
+pub struct TableColors {
+    /// Classes of cells by rows. A cell of several bytes has a class only if all its bytes are of
+    /// that class.
+    pub cells: Vec<Vec<Option<ByteClass>>>,
+    /// Classes of characters of the side panel by rows.
+    pub side_panel: Vec<Vec<Option<ByteClass>>>,
+}
 
-    BigTable { table: Table },
+pub enum OtherInfo {
+    Table2Column {
+        data: Vec<(String, String)>,
+    },
+
+    /// A table of bytes. If there's a side panel, its lines are shown to the right of rows. If
+    /// there're colors, bytes can be colored by their classes unless the terminal turns colors off.
+    BigTable {
+        table: Table,
+        side_panel: Option<Vec<String>>,
+        colors: Option<TableColors>,
+    },
+
+    /// Ranges where two buffers or files differ.
+    Diff {
+        left_name: String,
+        right_name: String,
+        ranges: Vec<DiffRange>,
+    },
 }
 
 pub type InfoLine = String;
@@ -105,6 +209,12 @@ pub struct CoreOutput {
     other_info: Vec<OtherInfo>,
 }
 
+impl Default for CoreOutput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CoreOutput {
     /// The `new` function creates empty `CoreOutput`: no info, no warning, no other info, etc.
     pub fn new() -> Self {
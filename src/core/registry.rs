@@ -0,0 +1,616 @@
+use super::checksum::Algorithm;
+use super::encoding::Encoding;
+use super::integer::{Endianness, IntType};
+use super::settings::Setting;
+use super::token::Token;
+use super::view::View;
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The `ArgumentKind` enumeration is what an argument of command must be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgumentKind {
+    Name,
+    UInt,
+    Byte,
+    /// An integer that may be negative.
+    Integer,
+    String,
+    /// A name, a word starting with `/`, `./` or `../` or a string.
+    Path,
+    IntType,
+    Endianness,
+    Algorithm,
+    /// The given word. If successive optional parameters are omitted, the following flag may be
+    /// written in place of the first one.
+    Flag(&'static str),
+    /// A byte, the wildcard `??` or an ASCII string.
+    Pattern,
+    /// A byte or an ASCII string whose characters are taken as bytes.
+    Data,
+    Encoding,
+    /// A view of `show_buffer` and `show_file` like `bin` or `u32le`.
+    View,
+    /// A name of option of the `set` command.
+    Option,
+    /// A value of option. Which values are allowed depends on the option.
+    OptionValue,
+    Punct(&'static str),
+}
+
+impl ArgumentKind {
+    /// The `accepts` function checks whether the token is an argument of this kind.
+    pub fn accepts(&self, token: &Token) -> bool {
+        match (self, token) {
+            (ArgumentKind::Name, Token::Word(_)) => true,
+            (ArgumentKind::UInt, Token::UInt(_)) => true,
+            (ArgumentKind::Byte, Token::UInt(value)) => *value < 256,
+            (ArgumentKind::Integer, Token::UInt(_) | Token::SInt(_)) => true,
+            (ArgumentKind::String, Token::Str(_)) => true,
+            (ArgumentKind::Path, Token::Word(_) | Token::Str(_)) => true,
+            (ArgumentKind::IntType, Token::Word(name)) => IntType::from_name(name).is_some(),
+            (ArgumentKind::Endianness, Token::Word(name)) => Endianness::from_name(name).is_some(),
+            (ArgumentKind::Algorithm, Token::Word(name)) => Algorithm::from_name(name).is_some(),
+            (ArgumentKind::Flag(flag), Token::Word(word)) => word == flag,
+            (ArgumentKind::Pattern, Token::UInt(value)) => *value < 256,
+            (ArgumentKind::Pattern, Token::Wildcard) => true,
+            (ArgumentKind::Pattern, Token::Str(string)) => string.is_ascii(),
+            (ArgumentKind::Data, Token::UInt(value)) => *value < 256,
+            (ArgumentKind::Data, Token::Str(string)) => string.is_ascii(),
+            (ArgumentKind::Encoding, Token::Word(name)) => Encoding::from_name(name).is_some(),
+            (ArgumentKind::View, Token::Word(name)) => View::from_name(name).is_some(),
+            (ArgumentKind::Option, Token::Word(name)) => Setting::is_option(name),
+            (ArgumentKind::OptionValue, Token::Word(_) | Token::UInt(_)) => true,
+            (ArgumentKind::Punct(punct), Token::Punct(got)) => punct == got,
+            _ => false,
+        }
+    }
+}
+
+impl Display for ArgumentKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ArgumentKind::Name => write!(f, "a name"),
+            ArgumentKind::UInt => write!(f, "an unsigned integer"),
+            ArgumentKind::Byte => write!(f, "a byte"),
+            ArgumentKind::Integer => write!(f, "an integer"),
+            ArgumentKind::String => write!(f, "a string"),
+            ArgumentKind::Path => write!(f, "a path"),
+            ArgumentKind::IntType => {
+                write!(f, "an integer type (u8, u16, u32, u64, i16, i32, i64)")
+            }
+            ArgumentKind::Endianness => write!(f, "a byte order (le, be)"),
+            ArgumentKind::Algorithm => write!(
+                f,
+                "an algorithm (crc32, crc16, adler32, sum8, sum16, sum32, xor8, sha256, md5)"
+            ),
+            ArgumentKind::Flag(flag) => write!(f, "{} or nothing", flag),
+            ArgumentKind::Pattern => write!(f, "a byte, ?? or an ASCII string"),
+            ArgumentKind::Data => write!(f, "a byte or an ASCII string"),
+            ArgumentKind::Encoding => write!(f, "an encoding (ascii, utf8, utf16le, utf16be)"),
+            ArgumentKind::View => write!(
+                f,
+                "a view (hex, bin, oct, dec, u16le, i32be, f32le, f64be, etc.)"
+            ),
+            ArgumentKind::Option => write!(f, "an option (columns, group, offsets, ascii, color)"),
+            ArgumentKind::OptionValue => write!(f, "a value of the option (see help set)"),
+            ArgumentKind::Punct(punct) => write!(f, "{}", punct),
+        }
+    }
+}
+
+/// The `Presence` enumeration tells how many arguments a parameter takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Presence {
+    Required,
+    Optional,
+    /// Any count of arguments. Only the last parameter can be variadic.
+    Variadic,
+}
+
+#[derive(Debug)]
+pub struct Parameter {
+    pub name: &'static str,
+    pub kind: ArgumentKind,
+    pub presence: Presence,
+}
+
+impl Parameter {
+    pub const fn required(name: &'static str, kind: ArgumentKind) -> Self {
+        Parameter {
+            name,
+            kind,
+            presence: Presence::Required,
+        }
+    }
+
+    pub const fn optional(name: &'static str, kind: ArgumentKind) -> Self {
+        Parameter {
+            name,
+            kind,
+            presence: Presence::Optional,
+        }
+    }
+
+    pub const fn variadic(name: &'static str, kind: ArgumentKind) -> Self {
+        Parameter {
+            name,
+            kind,
+            presence: Presence::Variadic,
+        }
+    }
+
+    pub const fn flag(flag: &'static str) -> Self {
+        Parameter::optional(flag, ArgumentKind::Flag(flag))
+    }
+
+    /// The `template` function returns the parameter as it's written in templates of commands
+    /// without brackets of optional ones.
+    pub fn template(&self) -> String {
+        match self.kind {
+            ArgumentKind::Flag(flag) | ArgumentKind::Punct(flag) => flag.to_string(),
+            ArgumentKind::String => format!("\"<{}>\"", self.name),
+            _ if self.presence == Presence::Variadic => format!("<...{}>", self.name),
+            _ => format!("<{}>", self.name),
+        }
+    }
+}
+
+/// The `CommandInfo` structure describes a command: its parameters, what it does and how it's
+/// used. Help messages and parsing of commands are based on it.
+#[derive(Debug)]
+pub struct CommandInfo {
+    pub name: &'static str,
+    pub parameters: &'static [Parameter],
+    pub description: &'static str,
+    pub examples: &'static [&'static str],
+}
+
+impl CommandInfo {
+    /// The `template` function returns the command as it should be called. Successive optional
+    /// parameters are enclosed in one pair of brackets. For example,
+    /// `checksum_write <algorithm> <name> <start> <end> <buffer_name> <index> [<type> <order>]`.
+    /// A flag starts new brackets, so it goes together with optional parameters following it:
+    /// `open_file <path> [as <alias>]`.
+    pub fn template(&self) -> String {
+        let mut template = self.name.to_string();
+        let mut in_brackets = false;
+
+        for parameter in self.parameters {
+            let optional = parameter.presence == Presence::Optional;
+            let flag = matches!(parameter.kind, ArgumentKind::Flag(_));
+
+            if in_brackets && (!optional || flag) {
+                template.push(']');
+                in_brackets = false;
+            }
+            template.push(' ');
+
+            if optional && !in_brackets {
+                template.push('[');
+                in_brackets = true;
+            }
+            template.push_str(&parameter.template());
+        }
+
+        if in_brackets {
+            template.push(']');
+        }
+
+        template
+    }
+
+    /// The `parameter` function returns the parameter that takes the argument at given position.
+    /// Positions are counted from one. Arguments beyond the last parameter belong to it if it's
+    /// variadic.
+    pub fn parameter(&self, position: usize) -> Option<&'static Parameter> {
+        let parameters: &'static [Parameter] = self.parameters;
+
+        match parameters.get(position.checked_sub(1)?) {
+            Some(parameter) => Some(parameter),
+            None => parameters
+                .last()
+                .filter(|parameter| parameter.presence == Presence::Variadic),
+        }
+    }
+}
+
+/// The `find_command` function returns description of the built-in command with given name.
+pub fn find_command(name: &str) -> Option<&'static CommandInfo> {
+    COMMANDS.iter().find(|info| info.name == name)
+}
+
+/// All commands executed by the core in the order they're listed by `help`.
+pub static COMMANDS: [CommandInfo; 40] = [
+    CommandInfo {
+        name: "make_buffer",
+        parameters: &[
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::required("buffer_size", ArgumentKind::UInt),
+        ],
+        description: "creates a buffer named <buffer_name> and sized <buffer_size>.",
+        examples: &["make_buffer mbr 512"],
+    },
+    CommandInfo {
+        name: "fill_buffer",
+        parameters: &[
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::required("value", ArgumentKind::Byte),
+            Parameter::required("start", ArgumentKind::UInt),
+            Parameter::required("end", ArgumentKind::UInt),
+        ],
+        description: "fills bytes from <start> inclusive to <end> not inclusive (bytes are counted from zero) of the buffer with the value <value>.",
+        examples: &["fill_buffer mbr 0 0 446"],
+    },
+    CommandInfo {
+        name: "show_buffer",
+        parameters: &[
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::required("start", ArgumentKind::UInt),
+            Parameter::required("end", ArgumentKind::UInt),            Parameter::optional("view", ArgumentKind::View),
+        ],
+        description: "outputs the contents of the buffer <buffer_name> to the console starting from <start> inclusive and up to <end> not inclusive (bytes are counted from zero). The bytes are shown as hexadecimal by default; the <view> may show them as binary (bin), octal (oct) or decimal (dec) bytes or as integers and floats of given width and byte order (u16le, i32be, f32le, f64be, etc.).",
+        examples: &["show_buffer mbr 0 512", "show_buffer mbr 446 size(mbr)", "show_buffer mbr 446 510 u32le"],
+    },
+    CommandInfo {
+        name: "buffer_info",
+        parameters: &[Parameter::required("buffer_name", ArgumentKind::Name)],
+        description: "gives information about the buffer <buffer_name>.",
+        examples: &["buffer_info mbr"],
+    },
+    CommandInfo {
+        name: "copy_buffer",
+        parameters: &[
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::required("new_buffer_name", ArgumentKind::Name),
+        ],
+        description: "creates a new buffer <new_buffer_name> with the same size and bytes as the buffer <buffer_name>. A variable with the name <new_buffer_name> is replaced with a warning.",
+        examples: &["copy_buffer mbr mbr_backup"],
+    },
+    CommandInfo {
+        name: "buffer_set_byte",
+        parameters: &[
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::required("index", ArgumentKind::UInt),
+            Parameter::required("value", ArgumentKind::Byte),
+        ],
+        description: "sets the value of the index <index> of the buffer <buffer_name> to the value <value>.",
+        examples: &["buffer_set_byte mbr 446 0x80"],
+    },
+    CommandInfo {
+        name: "create_file",
+        parameters: &[
+            Parameter::required("path", ArgumentKind::Path),
+            Parameter::required("file_size", ArgumentKind::UInt),
+            Parameter::flag("overwrite"),
+            Parameter::flag("as"),
+            Parameter::optional("alias", ArgumentKind::Name),
+        ],
+        description: "creates a file <path> with the size <file_size> and names it <path> or, if as is written, <alias>. If overwrite is written, an existing file is truncated. A variable with the same name is replaced with a warning.",
+        examples: &[
+            "create_file disk.img 1048576",
+            "create_file disk.img 512 overwrite",
+            "create_file \"/tmp/new disk.img\" 512 as disk",
+        ],
+    },
+    CommandInfo {
+        name: "from_file_to_buffer",
+        parameters: &[
+            Parameter::required("file_name", ArgumentKind::Name),
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::required("bytes_count", ArgumentKind::UInt),
+            Parameter::required("file_start", ArgumentKind::UInt),
+            Parameter::required("buffer_start", ArgumentKind::UInt),
+        ],
+        description: "copies bytes in the amount of <bytes_count> pieces from the file <file_name> starting from the <file_start>th byte (bytes count from zero) to the buffer, which is being written starting from the <buffer_start> th byte.",
+        examples: &["from_file_to_buffer disk.img mbr 512 0 0"],
+    },
+    CommandInfo {
+        name: "from_buffer_to_file",
+        parameters: &[
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::required("file_name", ArgumentKind::Name),
+            Parameter::required("bytes_count", ArgumentKind::UInt),
+            Parameter::required("buffer_start", ArgumentKind::UInt),
+            Parameter::required("file_start", ArgumentKind::UInt),
+        ],
+        description: "copies bytes in the amount of <bytes_count> pieces from the buffer <buffer_name> starting from the <buffer_start>th byte (bytes are counted from zero) to the file <file_name>, which is recorded starting from <file_start>.",
+        examples: &["from_buffer_to_file mbr disk.img 512 0 0"],
+    },
+    CommandInfo {
+        name: "buffer_write_bytes",
+        parameters: &[
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::required("start", ArgumentKind::UInt),
+            Parameter::variadic("bytes", ArgumentKind::Data),
+        ],
+        description: "writes a sequence of bytes <...bytes> (bytes are separated by a space) in the buffer <buffer_name> starting from the position <start> (bytes count starts from zero). ASCII strings are written byte by byte.",
+        examples: &[
+            "buffer_write_bytes mbr 510 0x55 0xAA",
+            "buffer_write_bytes boot 0 0xEB 0x58 0x90 \"MSDOS5.0\"",
+        ],
+    },
+    CommandInfo {
+        name: "buffer_write_int",
+        parameters: &[
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::required("index", ArgumentKind::UInt),
+            Parameter::required("type", ArgumentKind::IntType),
+            Parameter::required("order", ArgumentKind::Endianness),
+            Parameter::required("value", ArgumentKind::Integer),
+        ],
+        description: "writes the integer <value> of the type <type> (u8, u16, u32, u64, i16, i32 or i64) in the byte order <order> (le or be) to the buffer <buffer_name> starting from the position <index>.",
+        examples: &["buffer_write_int mbr 454 u32 le 2048", "buffer_write_int mbr 0 i16 be -2"],
+    },
+    CommandInfo {
+        name: "buffer_write_str",
+        parameters: &[
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::required("index", ArgumentKind::UInt),
+            Parameter::required("text", ArgumentKind::String),
+            Parameter::optional("encoding", ArgumentKind::Encoding),
+            Parameter::flag("nul"),
+        ],
+        description: "writes the text <text> in the encoding <encoding> (ascii by default, utf8, utf16le or utf16be) to the buffer <buffer_name> starting from the position <index>. If nul is written, the text is followed by the terminating nul.",
+        examples: &[
+            "buffer_write_str boot 82 \"FAT32   \"",
+            "buffer_write_str label 0 \"Disk\" utf16le nul",
+        ],
+    },
+    CommandInfo {
+        name: "buffer_resize",
+        parameters: &[
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::required("new_size", ArgumentKind::UInt),
+        ],
+        description: "changes the size of the buffer <buffer_name> to <new_size>. New bytes are 0; bytes beyond the new size are deleted.",
+        examples: &["buffer_resize image 4096"],
+    },
+    CommandInfo {
+        name: "buffer_insert",
+        parameters: &[
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::required("offset", ArgumentKind::UInt),
+            Parameter::variadic("bytes", ArgumentKind::Data),
+        ],
+        description: "inserts a sequence of bytes <...bytes> to the buffer <buffer_name> at the position <offset>, moving the following bytes forward. The buffer grows; <offset> may be equal to its size.",
+        examples: &["buffer_insert image 0 0x7F \"ELF\""],
+    },
+    CommandInfo {
+        name: "buffer_delete",
+        parameters: &[
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::required("start", ArgumentKind::UInt),
+            Parameter::required("end", ArgumentKind::UInt),
+        ],
+        description: "deletes bytes of the buffer <buffer_name> starting with <start> inclusive and ending with <end> not inclusive, moving the following bytes back. The buffer shrinks.",
+        examples: &["buffer_delete image 0 512"],
+    },
+    CommandInfo {
+        name: "buffer_append",
+        parameters: &[
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::variadic("bytes", ArgumentKind::Data),
+        ],
+        description: "writes a sequence of bytes <...bytes> after the end of the buffer <buffer_name>. The buffer grows.",
+        examples: &["buffer_append image 0x55 0xAA"],
+    },
+    CommandInfo {
+        name: "buffer_read_int",
+        parameters: &[
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::required("index", ArgumentKind::UInt),
+            Parameter::required("type", ArgumentKind::IntType),
+            Parameter::required("order", ArgumentKind::Endianness),
+        ],
+        description: "prints the integer of the type <type> in the byte order <order> read from the buffer <buffer_name> starting from the position <index>.",
+        examples: &["buffer_read_int mbr 454 u32 le"],
+    },
+    CommandInfo {
+        name: "pull_out_slice",
+        parameters: &[
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::required("new_buffer_name", ArgumentKind::Name),
+            Parameter::required("start", ArgumentKind::UInt),
+            Parameter::required("end", ArgumentKind::UInt),
+        ],
+        description: "creates a new buffer <new_buffer_name> with the exact size of <end> - <start> bytes and writes there the values from the buffer <buffer_name> starting with <start> and ending with <end>. A variable with the same name is replaced with a warning.",
+        examples: &["pull_out_slice mbr partitions 446 510"],
+    },
+    CommandInfo {
+        name: "merge_buffers",
+        parameters: &[
+            Parameter::required("left_buffer_name", ArgumentKind::Name),
+            Parameter::required("right_buffer_name", ArgumentKind::Name),
+            Parameter::required("new_buffer_name", ArgumentKind::Name),
+        ],
+        description: "creates a new buffer <new_buffer_name>, in which the first part of the bytes is copied from <left_buffer_name>, and the second part is copied from the buffer <right_buffer_name>. That is, the size of the new buffer is equal to the sum of the other two. A variable with the same name is replaced with a warning.",
+        examples: &["merge_buffers mbr gpt image"],
+    },
+    CommandInfo {
+        name: "turn_buffer_to_file",
+        parameters: &[
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::required("file_name", ArgumentKind::Path),
+            Parameter::flag("overwrite"),
+        ],
+        description: "creates a file <file_name> with the same bytes as in the buffer <buffer_name>. If overwrite is written, an existing file is truncated.",
+        examples: &["turn_buffer_to_file mbr mbr.bin", "turn_buffer_to_file mbr mbr.bin overwrite"],
+    },
+    CommandInfo {
+        name: "turn_file_to_buffer",
+        parameters: &[
+            Parameter::required("file_name", ArgumentKind::Name),
+            Parameter::required("new_buffer_name", ArgumentKind::Name),
+        ],
+        description: "creates a new buffer <new_buffer_name> with all bytes of the opened file <file_name>. A variable with the same name is replaced with a warning.",
+        examples: &["turn_file_to_buffer disk image"],
+    },
+    CommandInfo {
+        name: "save_buffer",
+        parameters: &[
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::required("file_name", ArgumentKind::Path),
+        ],
+        description: "writes all bytes of the buffer <buffer_name> to the file <file_name>. An existing file is replaced atomically.",
+        examples: &["save_buffer mbr mbr.bin"],
+    },
+    CommandInfo {
+        name: "open_file",
+        parameters: &[
+            Parameter::required("path", ArgumentKind::Path),
+            Parameter::flag("as"),
+            Parameter::optional("alias", ArgumentKind::Name),
+        ],
+        description: "opens the file <path> strictly for reading and names it <path> or, if as is written, <alias>. A variable with the same name is replaced with a warning.",
+        examples: &[
+            "open_file disk.img",
+            "open_file ../build/boot.bin",
+            "open_file \"/mnt/images/boot disk.img\" as boot",
+        ],
+    },
+    CommandInfo {
+        name: "open_file_rw",
+        parameters: &[
+            Parameter::required("path", ArgumentKind::Path),
+            Parameter::flag("backup"),
+            Parameter::flag("as"),
+            Parameter::optional("alias", ArgumentKind::Name),
+        ],
+        description: "opens the existing file <path> for reading and writing and names it <path> or, if as is written, <alias>. If backup is written, the file is copied to <path>.bak first. A variable with the same name is replaced with a warning.",
+        examples: &["open_file_rw disk.img backup", "open_file_rw /dev/sdb as disk"],
+    },
+    CommandInfo {
+        name: "show_file",
+        parameters: &[
+            Parameter::required("file_name", ArgumentKind::Name),
+            Parameter::required("start", ArgumentKind::UInt),
+            Parameter::required("end", ArgumentKind::UInt),            Parameter::optional("view", ArgumentKind::View),
+        ],
+        description: "reads a file named <file_name> starting with <start> and ending with <end>. The <view> is the same as for show_buffer.",
+        examples: &["show_file disk.img 0 512", "show_file disk.img 0 64 bin"],
+    },
+    CommandInfo {
+        name: "diff",
+        parameters: &[
+            Parameter::required("left_name", ArgumentKind::Name),
+            Parameter::required("right_name", ArgumentKind::Name),
+        ],
+        description: "compares two buffers or files <left_name> and <right_name> and prints ranges where they differ side by side.",
+        examples: &["diff mbr disk.img"],
+    },
+    CommandInfo {
+        name: "checksum",
+        parameters: &[
+            Parameter::required("algorithm", ArgumentKind::Algorithm),
+            Parameter::required("name", ArgumentKind::Name),
+            Parameter::required("start", ArgumentKind::UInt),
+            Parameter::required("end", ArgumentKind::UInt),
+        ],
+        description: "prints the checksum <algorithm> (crc32, crc16, adler32, sum8, sum16, sum32, xor8, sha256 or md5) of bytes of the buffer or the file <name> starting with <start> and ending with <end>.",
+        examples: &["checksum crc32 disk.img 0 size(disk.img)"],
+    },
+    CommandInfo {
+        name: "checksum_write",
+        parameters: &[
+            Parameter::required("algorithm", ArgumentKind::Algorithm),
+            Parameter::required("name", ArgumentKind::Name),
+            Parameter::required("start", ArgumentKind::UInt),
+            Parameter::required("end", ArgumentKind::UInt),
+            Parameter::required("buffer_name", ArgumentKind::Name),
+            Parameter::required("index", ArgumentKind::UInt),
+            Parameter::optional("type", ArgumentKind::IntType),
+            Parameter::optional("order", ArgumentKind::Endianness),
+        ],
+        description: "writes the checksum to the buffer <buffer_name> starting from the position <index> as the integer of the type <type> in the byte order <order>. Hashes are written as they are.",
+        examples: &["checksum_write crc32 image 16 4096 image 12 u32 be"],
+    },
+    CommandInfo {
+        name: "find_bytes",
+        parameters: &[
+            Parameter::required("name", ArgumentKind::Name),
+            Parameter::variadic("pattern", ArgumentKind::Pattern),
+        ],
+        description: "prints offsets of all occurrences of the pattern <...pattern> in the buffer or the file <name>. The pattern consists of bytes, wildcards ?? matching any byte and ASCII strings in double quotes.",
+        examples: &["find_bytes mbr 0x55 0xAA", "find_bytes disk.img 0xEB ?? 0x90 \"MSDOS\""],
+    },
+    CommandInfo {
+        name: "find_all",
+        parameters: &[Parameter::variadic("pattern", ArgumentKind::Pattern)],
+        description: "prints offsets of all occurrences of the pattern <...pattern> in all buffers and files.",
+        examples: &["find_all \"FAT32\""],
+    },
+    CommandInfo {
+        name: "list",
+        parameters: &[],
+        description: "lists all variables: their names, kinds and sizes. For files, the path and the mode (read or read-write) are shown too.",
+        examples: &["list"],
+    },
+    CommandInfo {
+        name: "drop",
+        parameters: &[Parameter::required("name", ArgumentKind::Name)],
+        description: "deletes the variable <name>. The command can be undone, so a file is closed only when the journal forgets it; the file stays in the directory.",
+        examples: &["drop mbr"],
+    },
+    CommandInfo {
+        name: "rename",
+        parameters: &[
+            Parameter::required("old_name", ArgumentKind::Name),
+            Parameter::required("new_name", ArgumentKind::Name),
+        ],
+        description: "gives the variable <old_name> the name <new_name>. A variable with the name <new_name> is replaced with a warning.",
+        examples: &["rename mbr boot_sector"],
+    },
+    CommandInfo {
+        name: "undo",
+        parameters: &[],
+        description: "undoes the last command that changed buffers or variables.",
+        examples: &["undo"],
+    },
+    CommandInfo {
+        name: "redo",
+        parameters: &[],
+        description: "redoes the last undone command.",
+        examples: &["redo"],
+    },
+    CommandInfo {
+        name: "history",
+        parameters: &[],
+        description: "lists the recorded commands that can be undone or redone.",
+        examples: &["history"],
+    },
+    CommandInfo {
+        name: "history_set_depth",
+        parameters: &[Parameter::required("depth", ArgumentKind::UInt)],
+        description: "sets the count of commands the history keeps to <depth>.",
+        examples: &["history_set_depth 1000"],
+    },
+    CommandInfo {
+        name: "set",
+        parameters: &[
+            Parameter::required("option", ArgumentKind::Option),
+            Parameter::required("value", ArgumentKind::OptionValue),
+        ],
+        description: "sets the option <option> to <value>. Options of hex dumps: columns (count of bytes in a row from 1 to 64, 16 by default), group (count of bytes written together: 1, 2, 4 or 8), offsets (hex or dec), ascii (the column of characters: on or off) and color (coloring bytes by classes and differences of diff: on, off or auto; auto colors only a terminal without NO_COLOR set).",
+        examples: &["set columns 8", "set group 4", "set offsets hex", "set ascii off", "set color on"],
+    },
+    CommandInfo {
+        name: "assert",
+        parameters: &[
+            Parameter::required("condition", ArgumentKind::Integer),
+            Parameter::optional("message", ArgumentKind::String),
+        ],
+        description: "reports the error with the message <message> if the expression <condition> is 0.",
+        examples: &["assert size(mbr) == 512 \"the MBR must be 512 bytes\""],
+    },
+    CommandInfo {
+        name: "let",
+        parameters: &[
+            Parameter::required("name", ArgumentKind::Name),
+            Parameter::required("=", ArgumentKind::Punct("=")),
+            Parameter::required("expression", ArgumentKind::Integer),
+        ],
+        description: "binds the value of the expression <expression> to the integer variable <name>. Expressions may be used instead of any integer, for example size(mbr) - 2.",
+        examples: &["let lba = 2048", "let end = size(mbr) - 2"],
+    },
+];
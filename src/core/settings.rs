@@ -0,0 +1,181 @@
+use super::token::Token;
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The `MAX_COLUMNS` is the greatest count of bytes in a row of hex dumps.
+pub const MAX_COLUMNS: usize = 64;
+/// The `GROUPS` are counts of bytes that can be written together in hex dumps.
+pub const GROUPS: [usize; 4] = [1, 2, 4, 8];
+/// The `OPTIONS` are names of options changed by the `set` command.
+pub const OPTIONS: [&str; 5] = ["columns", "group", "offsets", "ascii", "color"];
+
+const ON: &str = "on";
+const OFF: &str = "off";
+
+/// The `Radix` enumeration is the base offsets are written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Radix {
+    Hex,
+    Dec,
+}
+
+impl Radix {
+    pub fn from_name(name: &str) -> Option<Radix> {
+        match name {
+            "hex" => Some(Radix::Hex),
+            "dec" => Some(Radix::Dec),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Radix::Hex => "hex",
+            Radix::Dec => "dec",
+        }
+    }
+
+    /// The `format` function writes the offset in the radix. Hexadecimal offsets start with `0x`.
+    pub fn format(&self, offset: usize) -> String {
+        match self {
+            Radix::Hex => format!("{offset:#x}"),
+            Radix::Dec => offset.to_string(),
+        }
+    }
+}
+
+/// The `ColorMode` enumeration tells whether hex dumps are colored by classes of bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMode {
+    On,
+    Off,
+    /// The terminal decides: colors are used if its output is a terminal and `NO_COLOR` isn't set.
+    Auto,
+}
+
+impl ColorMode {
+    pub fn from_name(name: &str) -> Option<ColorMode> {
+        match name {
+            ON => Some(ColorMode::On),
+            OFF => Some(ColorMode::Off),
+            "auto" => Some(ColorMode::Auto),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ColorMode::On => ON,
+            ColorMode::Off => OFF,
+            ColorMode::Auto => "auto",
+        }
+    }
+}
+
+/// The `Settings` structure keeps options of the core changing how its output looks.
+#[derive(Debug, Clone)]
+pub struct Settings {
+    /// Count of bytes in a row of hex dumps.
+    pub columns: usize,
+    /// Count of bytes written together without spaces in hex dumps.
+    pub group: usize,
+    /// The base offsets of rows of hex dumps are written in.
+    pub offsets: Radix,
+    /// Whether hex dumps have the column of characters.
+    pub ascii: bool,
+    /// Whether hex dumps are colored by classes of bytes.
+    pub color: ColorMode,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            columns: 16,
+            group: 1,
+            offsets: Radix::Dec,
+            ascii: true,
+            color: ColorMode::Auto,
+        }
+    }
+}
+
+impl Settings {
+    /// The `apply` function changes the option.
+    pub fn apply(&mut self, setting: Setting) {
+        match setting {
+            Setting::Columns(columns) => self.columns = columns,
+            Setting::Group(group) => self.group = group,
+            Setting::Offsets(radix) => self.offsets = radix,
+            Setting::Ascii(ascii) => self.ascii = ascii,
+            Setting::Color(color) => self.color = color,
+        }
+    }
+}
+
+/// The `Setting` enumeration is a new value of one of options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Setting {
+    Columns(usize),
+    Group(usize),
+    Offsets(Radix),
+    Ascii(bool),
+    Color(ColorMode),
+}
+
+impl Setting {
+    /// The `is_option` function checks whether there's an option with given name.
+    pub fn is_option(name: &str) -> bool {
+        OPTIONS.contains(&name)
+    }
+
+    /// The `parse` function returns new value of the option with given name. If there's no such
+    /// option or the value isn't allowed for it, the function returns `None`.
+    ///
+    /// Allowed values:
+    /// - `columns`: from 1 to `MAX_COLUMNS`.
+    /// - `group`: 1, 2, 4 or 8.
+    /// - `offsets`: `hex` or `dec`.
+    /// - `ascii`: `on` or `off`.
+    /// - `color`: `on`, `off` or `auto`.
+    pub fn parse(option: &str, value: &Token) -> Option<Setting> {
+        match (option, value) {
+            ("columns", Token::UInt(columns)) if (1..=MAX_COLUMNS).contains(columns) => {
+                Some(Setting::Columns(*columns))
+            }
+            ("group", Token::UInt(group)) if GROUPS.contains(group) => Some(Setting::Group(*group)),
+            ("offsets", Token::Word(name)) => Radix::from_name(name).map(Setting::Offsets),
+            ("ascii", Token::Word(word)) if word == ON => Some(Setting::Ascii(true)),
+            ("ascii", Token::Word(word)) if word == OFF => Some(Setting::Ascii(false)),
+            ("color", Token::Word(name)) => ColorMode::from_name(name).map(Setting::Color),
+            _ => None,
+        }
+    }
+
+    pub fn option(&self) -> &'static str {
+        match self {
+            Setting::Columns(_) => "columns",
+            Setting::Group(_) => "group",
+            Setting::Offsets(_) => "offsets",
+            Setting::Ascii(_) => "ascii",
+            Setting::Color(_) => "color",
+        }
+    }
+
+    /// The `value` function returns the value as it's written in the `set` command.
+    pub fn value(&self) -> String {
+        match self {
+            Setting::Columns(value) | Setting::Group(value) => value.to_string(),
+            Setting::Offsets(radix) => radix.name().to_string(),
+            Setting::Ascii(true) => ON.to_string(),
+            Setting::Ascii(false) => OFF.to_string(),
+            Setting::Color(color) => color.name().to_string(),
+        }
+    }
+}
+
+impl Display for Setting {
+    /// The setting is written as option and value separated by a space: `columns 16`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} {}", self.option(), self.value())
+    }
+}
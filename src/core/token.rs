@@ -1,7 +1,18 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Token {
     UInt(usize),
+    /// A negative integer. Integers without the minus sign are always `UInt`.
+    SInt(isize),
     Word(String),
+    /// A string literal written in double quotes. Quotes aren't kept.
+    Str(String),
+    /// The `??` token that matches any byte.
+    Wildcard,
+    /// Punctuation of expressions and blocks: an operator, a parenthesis, a brace, a comma, the
+    /// equals sign or the range `..`.
+    Punct(&'static str),
 }
 
 impl Token {
@@ -10,8 +21,42 @@ impl Token {
         Token::UInt(val)
     }
 
+    /// The `sinteger` function returns Token as signed integer.
+    pub fn sinteger(val: isize) -> Token {
+        Token::SInt(val)
+    }
+
     /// The `word` function returns Token as word.
     pub fn word(val: String) -> Token {
         Token::Word(val)
     }
+
+    /// The `string` function returns Token as string literal.
+    pub fn string(val: String) -> Token {
+        Token::Str(val)
+    }
+
+    /// The `wildcard` function returns Token as wildcard.
+    pub fn wildcard() -> Token {
+        Token::Wildcard
+    }
+
+    /// The `punct` function returns Token as punctuation.
+    pub fn punct(val: &'static str) -> Token {
+        Token::Punct(val)
+    }
+}
+
+impl Display for Token {
+    /// The `fmt` function writes the token as it's written in commands.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Token::UInt(value) => write!(f, "{}", value),
+            Token::SInt(value) => write!(f, "{}", value),
+            Token::Word(word) => write!(f, "{}", word),
+            Token::Str(string) => write!(f, "\"{}\"", string.escape_debug()),
+            Token::Wildcard => write!(f, "??"),
+            Token::Punct(punct) => write!(f, "{}", punct),
+        }
+    }
 }
@@ -1,5 +1,11 @@
+use super::checksum::Algorithm;
 use super::command::CoreCommand;
+use super::encoding::Encoding;
+use super::integer::{Endianness, IntType};
+use super::registry::{find_command, ArgumentKind, CommandInfo, Parameter, Presence};
+use super::settings::Setting;
 use super::token::Token;
+use super::view::View;
 
 use std::error::Error as ErrorTrait;
 use std::fmt::{Display, Formatter, Result as FmtResult};
@@ -8,18 +14,93 @@ pub type ParseResult<T> = Result<T, ParseError>;
 
 #[derive(Debug)]
 pub enum ParseError {
-    UnknownCommandTemplate,
+    UnknownCommand {
+        command: String,
+    },
+    /// The argument at the position is missing or isn't of the expected kind. Positions are
+    /// counted from one; the command's name is at zero.
+    InvalidArgument {
+        command: String,
+        position: usize,
+        parameter: &'static str,
+        expected: ArgumentKind,
+        got: Option<String>,
+    },
+    /// The argument at the position isn't allowed with the previous ones.
+    UnexpectedArgument {
+        command: String,
+        position: usize,
+        got: String,
+    },
 }
 
 impl ParseError {
-    pub fn unknown_command_template() -> Self {
-        ParseError::UnknownCommandTemplate
+    pub fn unknown_command(command: &str) -> Self {
+        ParseError::UnknownCommand {
+            command: command.to_string(),
+        }
+    }
+
+    pub fn invalid_argument(
+        command: &str,
+        position: usize,
+        parameter: &'static str,
+        expected: ArgumentKind,
+        got: Option<&Token>,
+    ) -> Self {
+        ParseError::InvalidArgument {
+            command: command.to_string(),
+            position,
+            parameter,
+            expected,
+            got: got.map(Token::to_string),
+        }
+    }
+
+    pub fn unexpected_argument(command: &str, position: usize, got: &Token) -> Self {
+        ParseError::UnexpectedArgument {
+            command: command.to_string(),
+            position,
+            got: got.to_string(),
+        }
     }
 }
 
 impl Display for ParseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "ParseError: {:?}", self)
+        write!(f, "ParseError: ")?;
+
+        match self {
+            ParseError::UnknownCommand { command } => write!(f, "UnknownCommand: {}", command),
+            ParseError::InvalidArgument {
+                command,
+                position,
+                parameter,
+                expected,
+                got,
+            } => {
+                write!(f, "{}: argument {} ", command, position)?;
+                // A flag or punctuation is the parameter itself.
+                if !matches!(expected, ArgumentKind::Flag(_) | ArgumentKind::Punct(_)) {
+                    write!(f, "<{}> ", parameter)?;
+                }
+                write!(
+                    f,
+                    "must be {}, got {}",
+                    expected,
+                    got.as_deref().unwrap_or("nothing")
+                )
+            }
+            ParseError::UnexpectedArgument {
+                command,
+                position,
+                got,
+            } => write!(
+                f,
+                "{}: argument {} is unexpected, got {}",
+                command, position, got
+            ),
+        }
     }
 }
 
@@ -29,212 +110,581 @@ fn is_byte(t: usize) -> bool {
     t < 256
 }
 
-/// The `parse_tokens` function takes sequence of tokens and makes on them core's command.
-pub fn parse_tokens(tokens: &[Token]) -> ParseResult<CoreCommand> {
-    let Some(Token::Word(command_name)) = tokens.get(0) else { return Ok(CoreCommand::Nop) };
+/// The `is_omitted` function checks whether the first of successive optional parameters is
+/// omitted: the token isn't its argument, but one of the flags following it.
+fn is_omitted(parameters: &[Parameter], token: &Token) -> bool {
+    parameters
+        .iter()
+        .take_while(|parameter| parameter.presence == Presence::Optional)
+        .position(|parameter| parameter.kind.accepts(token))
+        .is_some_and(|index| index > 0 && matches!(parameters[index].kind, ArgumentKind::Flag(_)))
+}
+
+/// The `ArgumentReader` structure takes arguments of command described by the registry. If an
+/// argument isn't of the expected kind, the error names the command, the position of the argument
+/// and its parameter.
+///
+/// Arguments are aligned with parameters: functions reading arguments take positions of
+/// parameters, which differ from positions of tokens if an optional parameter is omitted before a
+/// flag.
+struct ArgumentReader<'t> {
+    info: &'static CommandInfo,
+    tokens: &'t [Token],
+    /// Arguments with positions of their tokens. The argument at the index is taken by the
+    /// parameter at the same position; the command's name is at zero. Missing and omitted
+    /// arguments are `None`.
+    arguments: Vec<Option<(usize, &'t Token)>>,
+    /// The position of the first token that isn't taken by any parameter.
+    rest: usize,
+}
 
-    match command_name.as_str() {
-        "make_buffer" => {
-            let Some(Token::Word(buffer_name)) = tokens.get(1) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::UInt(buffer_size)) = tokens.get(2) else { return Err(ParseError::unknown_command_template()) };
+impl<'t> ArgumentReader<'t> {
+    fn new(info: &'static CommandInfo, tokens: &'t [Token]) -> Self {
+        let mut arguments = vec![tokens.first().map(|token| (0, token))];
+        let mut position = 1;
 
-            Ok(CoreCommand::MakeBuffer {
-                buffer_name: buffer_name.clone(),
-                buffer_size: *buffer_size,
-            })
+        for (index, parameter) in info.parameters.iter().enumerate() {
+            let token = tokens.get(position);
+
+            match token {
+                _ if parameter.presence == Presence::Variadic => {
+                    arguments.extend(tokens.iter().enumerate().skip(position).map(Some));
+                    position = position.max(tokens.len());
+                }
+                Some(token) if is_omitted(&info.parameters[index..], token) => arguments.push(None),
+                Some(token) => {
+                    arguments.push(Some((position, token)));
+                    position += 1;
+                }
+                None => arguments.push(None),
+            }
         }
-        "fill_buffer" => {
-            let Some(Token::Word(buffer_name)) = tokens.get(1) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::UInt(value)) = tokens.get(2) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::UInt(start)) = tokens.get(3) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::UInt(end)) = tokens.get(4) else { return Err(ParseError::unknown_command_template()) };
 
-            if *value > 255 {
-                return Err(ParseError::unknown_command_template());
+        ArgumentReader {
+            info,
+            tokens,
+            arguments,
+            rest: position,
+        }
+    }
+
+    fn get(&self, position: usize) -> Option<&'t Token> {
+        self.arguments
+            .get(position)
+            .copied()
+            .flatten()
+            .map(|(_, token)| token)
+    }
+
+    /// The `error` function returns error about the argument at the position. If the argument is
+    /// missing, the error tells the position after the last token.
+    ///
+    /// Accepted guarantees:
+    /// - There's a parameter taking the argument at the position.
+    fn error(&self, position: usize) -> ParseError {
+        let parameter = self.info.parameter(position).unwrap();
+        let token_position = match self.arguments.get(position).copied().flatten() {
+            Some((token_position, _)) => token_position,
+            None => self.tokens.len(),
+        };
+
+        ParseError::invalid_argument(
+            self.info.name,
+            token_position,
+            parameter.name,
+            parameter.kind,
+            self.get(position),
+        )
+    }
+
+    /// The `validate` function checks the count of arguments and their kinds against parameters
+    /// of the command.
+    fn validate(&self) -> ParseResult<()> {
+        for (position, argument) in self.arguments.iter().enumerate().skip(1) {
+            let parameter = self.info.parameter(position).unwrap();
+
+            match argument {
+                None if parameter.presence == Presence::Optional => {}
+                Some((_, token)) if parameter.kind.accepts(token) => {}
+                _ => return Err(self.error(position)),
             }
+        }
 
-            Ok(CoreCommand::FillBuffer {
-                buffer_name,
-                value: *value as u8,
-                start: *start,
-                end: *end,
-            })
+        match self.tokens.get(self.rest) {
+            Some(token) => Err(ParseError::unexpected_argument(
+                self.info.name,
+                self.rest,
+                token,
+            )),
+            None => Ok(()),
         }
-        "show_buffer" => {
-            let Some(Token::Word(buffer_name)) = tokens.get(1) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::UInt(start)) = tokens.get(2) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::UInt(end)) = tokens.get(3) else { return Err(ParseError::unknown_command_template()) };
+    }
 
-            Ok(CoreCommand::ShowBuffer {
-                buffer_name,
-                start: *start,
-                end: *end,
-            })
+    fn name(&self, position: usize) -> ParseResult<&'t str> {
+        match self.get(position) {
+            Some(Token::Word(name)) => Ok(name),
+            _ => Err(self.error(position)),
         }
-        "buffer_info" => {
-            let Some(Token::Word(buffer_name)) = tokens.get(1) else { return Err(ParseError::unknown_command_template()) };
+    }
 
-            Ok(CoreCommand::BufferInfo { buffer_name })
+    fn uint(&self, position: usize) -> ParseResult<usize> {
+        match self.get(position) {
+            Some(Token::UInt(value)) => Ok(*value),
+            _ => Err(self.error(position)),
         }
-        "buffer_set_byte" => {
-            let Some(Token::Word(buffer_name)) = tokens.get(1) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::UInt(index)) = tokens.get(2) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::UInt(value)) = tokens.get(3) else { return Err(ParseError::unknown_command_template()) };
+    }
 
-            if *value > 255 {
-                return Err(ParseError::unknown_command_template());
-            }
+    fn byte(&self, position: usize) -> ParseResult<u8> {
+        match self.get(position) {
+            Some(Token::UInt(value)) if is_byte(*value) => Ok(*value as u8),
+            _ => Err(self.error(position)),
+        }
+    }
 
-            Ok(CoreCommand::BufferSetByte {
-                buffer_name,
-                index: *index,
-                value: *value as u8,
-            })
+    fn integer(&self, position: usize) -> ParseResult<i128> {
+        match self.get(position) {
+            Some(Token::UInt(value)) => Ok(*value as i128),
+            Some(Token::SInt(value)) => Ok(*value as i128),
+            _ => Err(self.error(position)),
         }
-        "create_file" => {
-            let Some(Token::Word(file_name)) = tokens.get(1) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::UInt(file_size)) = tokens.get(2) else { return Err(ParseError::unknown_command_template()) };
+    }
 
-            Ok(CoreCommand::CreateFile {
-                file_name: file_name.clone(),
-                file_size: *file_size,
-            })
+    /// The `path` function takes the argument written either as a word or as a string.
+    fn path(&self, position: usize) -> ParseResult<&'t str> {
+        match self.get(position) {
+            Some(Token::Word(path) | Token::Str(path)) => Ok(path),
+            _ => Err(self.error(position)),
         }
-        "from_file_to_buffer" => {
-            let Some(Token::Word(file_name)) = tokens.get(1) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::Word(buffer_name)) = tokens.get(2) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::UInt(bytes_count)) = tokens.get(3) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::UInt(file_start)) = tokens.get(4) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::UInt(buffer_start)) = tokens.get(5) else { return Err(ParseError::unknown_command_template()) };
+    }
 
-            Ok(CoreCommand::FromFileToBuffer {
-                file_name,
-                buffer_name,
-                bytes_count: *bytes_count,
-                file_start: *file_start,
-                buffer_start: *buffer_start,
-            })
+    /// The `alias` function returns the name following the flag `as` at the position. If there's
+    /// no flag, the function returns `None`.
+    fn alias(&self, position: usize) -> ParseResult<Option<&'t str>> {
+        match self.flag(position)? {
+            true => self.name(position + 1).map(Some),
+            false => Ok(None),
         }
-        "from_buffer_to_file" => {
-            let Some(Token::Word(buffer_name)) = tokens.get(1) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::Word(file_name)) = tokens.get(2) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::UInt(bytes_count)) = tokens.get(3) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::UInt(buffer_start)) = tokens.get(4) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::UInt(file_start)) = tokens.get(5) else { return Err(ParseError::unknown_command_template()) };
+    }
 
-            Ok(CoreCommand::FromBufferToFile {
-                buffer_name,
-                file_name,
-                bytes_count: *bytes_count,
-                buffer_start: *buffer_start,
-                file_start: *file_start,
-            })
+    fn string(&self, position: usize) -> ParseResult<&'t str> {
+        match self.get(position) {
+            Some(Token::Str(string)) => Ok(string),
+            _ => Err(self.error(position)),
         }
-        "open_file" => {
-            let Some(Token::Word(file_name)) = tokens.get(1) else { return Err(ParseError::unknown_command_template()) };
+    }
 
-            Ok(CoreCommand::OpenFile {
-                file_name: file_name.clone(),
-            })
+    /// The `optional_string` function returns `None` if there's no argument at the position.
+    fn optional_string(&self, position: usize) -> ParseResult<Option<&'t str>> {
+        match self.get(position) {
+            Some(Token::Str(string)) => Ok(Some(string)),
+            None => Ok(None),
+            _ => Err(self.error(position)),
         }
-        "show_file" => {
-            let Some(Token::Word(file_name)) = tokens.get(1) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::UInt(start)) = tokens.get(2) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::UInt(end)) = tokens.get(3) else { return Err(ParseError::unknown_command_template()) };
+    }
 
-            Ok(CoreCommand::ShowFile {
-                file_name,
-                start: *start,
-                end: *end,
-            })
+    fn int_type(&self, position: usize) -> ParseResult<IntType> {
+        match self.get(position) {
+            Some(Token::Word(name)) => IntType::from_name(name),
+            _ => None,
+        }
+        .ok_or_else(|| self.error(position))
+    }
+
+    fn endianness(&self, position: usize) -> ParseResult<Endianness> {
+        match self.get(position) {
+            Some(Token::Word(name)) => Endianness::from_name(name),
+            _ => None,
+        }
+        .ok_or_else(|| self.error(position))
+    }
+
+    fn algorithm(&self, position: usize) -> ParseResult<Algorithm> {
+        match self.get(position) {
+            Some(Token::Word(name)) => Algorithm::from_name(name),
+            _ => None,
+        }
+        .ok_or_else(|| self.error(position))
+    }
+
+    /// The `flag` function checks whether the optional argument is the parameter's word. If
+    /// there's no argument, the function returns `false`.
+    fn flag(&self, position: usize) -> ParseResult<bool> {
+        let Some(token) = self.get(position) else {
+            return Ok(false);
+        };
+
+        match self.info.parameter(position) {
+            Some(parameter) if parameter.kind.accepts(token) => Ok(true),
+            _ => Err(self.error(position)),
+        }
+    }
+
+    /// The `encoding` function returns the encoding at the position. If there's no argument, the
+    /// function returns ASCII.
+    fn encoding(&self, position: usize) -> ParseResult<Encoding> {
+        match self.get(position) {
+            Some(Token::Word(name)) => Encoding::from_name(name),
+            None => Some(Encoding::Ascii),
+            _ => None,
+        }
+        .ok_or_else(|| self.error(position))
+    }
+
+    /// The `view` function returns the view at the position. If there's no argument, the function
+    /// returns the hexadecimal view.
+    fn view(&self, position: usize) -> ParseResult<View> {
+        match self.get(position) {
+            Some(Token::Word(name)) => View::from_name(name),
+            None => Some(View::Hex),
+            _ => None,
+        }
+        .ok_or_else(|| self.error(position))
+    }
+
+    /// The `setting` function returns the option at the position with its value following it.
+    fn setting(&self, position: usize) -> ParseResult<Setting> {
+        let option = self.name(position)?;
+        let value = self
+            .get(position + 1)
+            .ok_or_else(|| self.error(position + 1))?;
+
+        Setting::parse(option, value).ok_or_else(|| self.error(position + 1))
+    }
+
+    /// The `bytes` function takes all arguments starting from the position as bytes. Characters of
+    /// ASCII strings are taken as bytes too.
+    fn bytes(&self, start: usize) -> ParseResult<Vec<u8>> {
+        let mut bytes = Vec::new();
+
+        for position in start..self.arguments.len().max(start) {
+            match self.get(position) {
+                Some(Token::Str(string)) if string.is_ascii() => bytes.extend(string.bytes()),
+                _ => bytes.push(self.byte(position)?),
+            }
         }
-        "buffer_write_bytes" => {
-            let Some(Token::Word(buffer_name)) = tokens.get(1) else { return Err(ParseError::unknown_command_template()) };
-            let Some(Token::UInt(start)) = tokens.get(2) else { return Err(ParseError::unknown_command_template()) };
 
-            let Some(tokens) = tokens.get(3..) else { return Err(ParseError::unknown_command_template()) };
+        Ok(bytes)
+    }
 
-            let mut bytes: Vec<u8> = Vec::new();
+    /// The `pattern` function makes a pattern of bytes on all arguments starting from the
+    /// position. Bytes are taken from integers, wildcards and ASCII string literals. A wildcard is
+    /// turned into `None` that matches any byte.
+    fn pattern(&self, start: usize) -> ParseResult<Vec<Option<u8>>> {
+        let mut pattern = Vec::new();
 
-            for token in tokens {
-                match token {
-                    Token::UInt(byte) if is_byte(*byte) => {
-                        bytes.push(*byte as u8);
-                    }
-                    _ => return Err(ParseError::unknown_command_template()),
+        for position in start..self.arguments.len().max(start) {
+            match self.get(position) {
+                Some(Token::UInt(byte)) if is_byte(*byte) => pattern.push(Some(*byte as u8)),
+                Some(Token::Wildcard) => pattern.push(None),
+                Some(Token::Str(string)) if string.is_ascii() => {
+                    pattern.extend(string.bytes().map(Some));
                 }
+                _ => return Err(self.error(position)),
             }
+        }
 
-            Ok(CoreCommand::BufferWriteBytes {
-                buffer_name,
-                start: *start,
-                bytes,
+        if pattern.is_empty() {
+            return Err(self.error(start));
+        }
+
+        Ok(pattern)
+    }
+}
+
+/// The `validate_arguments` function checks the count of arguments and their kinds against
+/// parameters of given command. It's used for commands that aren't parsed by `parse_tokens`, like
+/// the runner's ones.
+pub fn validate_arguments(info: &'static CommandInfo, tokens: &[Token]) -> ParseResult<()> {
+    ArgumentReader::new(info, tokens).validate()
+}
+
+/// The `parse_tokens` function takes sequence of tokens and makes on them core's command.
+pub fn parse_tokens(tokens: &[Token]) -> ParseResult<CoreCommand<'_>> {
+    let Some(Token::Word(command_name)) = tokens.first() else {
+        return Ok(CoreCommand::Nop);
+    };
+    let Some(info) = find_command(command_name) else {
+        return Err(ParseError::unknown_command(command_name));
+    };
+    let args = ArgumentReader::new(info, tokens);
+    args.validate()?;
+
+    match info.name {
+        "make_buffer" => Ok(CoreCommand::MakeBuffer {
+            buffer_name: args.name(1)?.to_string(),
+            buffer_size: args.uint(2)?,
+        }),
+        "fill_buffer" => Ok(CoreCommand::FillBuffer {
+            buffer_name: args.name(1)?,
+            value: args.byte(2)?,
+            start: args.uint(3)?,
+            end: args.uint(4)?,
+        }),
+        "show_buffer" => Ok(CoreCommand::ShowBuffer {
+            buffer_name: args.name(1)?,
+            start: args.uint(2)?,
+            end: args.uint(3)?,
+            view: args.view(4)?,
+        }),
+        "buffer_info" => Ok(CoreCommand::BufferInfo {
+            buffer_name: args.name(1)?,
+        }),
+        "copy_buffer" => Ok(CoreCommand::CopyBuffer {
+            buffer_name: args.name(1)?,
+            new_buffer_name: args.name(2)?.to_string(),
+        }),
+        "buffer_set_byte" => Ok(CoreCommand::BufferSetByte {
+            buffer_name: args.name(1)?,
+            index: args.uint(2)?,
+            value: args.byte(3)?,
+        }),
+        "create_file" => {
+            let file_path = args.path(1)?;
+
+            Ok(CoreCommand::CreateFile {
+                file_path: file_path.to_string(),
+                file_name: args.alias(4)?.unwrap_or(file_path).to_string(),
+                file_size: args.uint(2)?,
+                overwrite: args.flag(3)?,
             })
         }
-        "merge_buffers" => {
-            let Some(Token::Word(left_buffer_name)) = tokens.get(1) else {
-                return Err(ParseError::unknown_command_template())
-            };
-            let Some(Token::Word(right_buffer_name)) = tokens.get(2) else {
-                return Err(ParseError::unknown_command_template())
-            };
-            let Some(Token::Word(new_buffer_name)) = tokens.get(3) else {
-                return Err(ParseError::unknown_command_template())
-            };
+        "from_file_to_buffer" => Ok(CoreCommand::FromFileToBuffer {
+            file_name: args.name(1)?,
+            buffer_name: args.name(2)?,
+            bytes_count: args.uint(3)?,
+            file_start: args.uint(4)?,
+            buffer_start: args.uint(5)?,
+        }),
+        "from_buffer_to_file" => Ok(CoreCommand::FromBufferToFile {
+            buffer_name: args.name(1)?,
+            file_name: args.name(2)?,
+            bytes_count: args.uint(3)?,
+            buffer_start: args.uint(4)?,
+            file_start: args.uint(5)?,
+        }),
+        "open_file" => {
+            let file_path = args.path(1)?;
 
-            Ok(CoreCommand::MergeBuffers {
-                left_buffer_name,
-                right_buffer_name,
-                new_buffer_name: new_buffer_name.clone(),
+            Ok(CoreCommand::OpenFile {
+                file_path: file_path.to_string(),
+                file_name: args.alias(2)?.unwrap_or(file_path).to_string(),
             })
         }
-        "pull_out_slice" => {
-            let Some(Token::Word(buffer_name)) = tokens.get(1) else {
-                return Err(ParseError::unknown_command_template())
-            };
-            let Some(Token::Word(new_buffer_name)) = tokens.get(2) else {
-                return Err(ParseError::unknown_command_template())
-            };
-            let Some(Token::UInt(start)) = tokens.get(3) else {
-                return Err(ParseError::unknown_command_template())
-            };
-            let Some(Token::UInt(end)) = tokens.get(4) else {
-                return Err(ParseError::unknown_command_template())
-            };
+        "open_file_rw" => {
+            let file_path = args.path(1)?;
 
-            Ok(CoreCommand::PullOutSlice {
-                buffer_name,
-                new_buffer_name: new_buffer_name.clone(),
-                start: *start,
-                end: *end,
+            Ok(CoreCommand::OpenFileRw {
+                file_path: file_path.to_string(),
+                file_name: args.alias(3)?.unwrap_or(file_path).to_string(),
+                backup: args.flag(2)?,
             })
         }
-        "turn_buffer_to_file" => {
-            let Some(Token::Word(buffer_name)) = tokens.get(1) else {
-                return Err(ParseError::unknown_command_template())
-            };
-            let Some(Token::Word(file_name)) = tokens.get(2) else {
-                return Err(ParseError::unknown_command_template())
+        "show_file" => Ok(CoreCommand::ShowFile {
+            file_name: args.name(1)?,
+            start: args.uint(2)?,
+            end: args.uint(3)?,
+            view: args.view(4)?,
+        }),
+        "buffer_write_bytes" => Ok(CoreCommand::BufferWriteBytes {
+            buffer_name: args.name(1)?,
+            start: args.uint(2)?,
+            bytes: args.bytes(3)?,
+        }),
+        "buffer_write_int" => Ok(CoreCommand::BufferWriteInt {
+            buffer_name: args.name(1)?,
+            index: args.uint(2)?,
+            int_type: args.int_type(3)?,
+            endianness: args.endianness(4)?,
+            value: args.integer(5)?,
+        }),
+        "buffer_write_str" => Ok(CoreCommand::BufferWriteStr {
+            buffer_name: args.name(1)?,
+            index: args.uint(2)?,
+            text: args.string(3)?,
+            encoding: args.encoding(4)?,
+            nul: args.flag(5)?,
+        }),
+        "buffer_resize" => Ok(CoreCommand::BufferResize {
+            buffer_name: args.name(1)?,
+            new_size: args.uint(2)?,
+        }),
+        "buffer_insert" => Ok(CoreCommand::BufferInsert {
+            buffer_name: args.name(1)?,
+            offset: args.uint(2)?,
+            bytes: args.bytes(3)?,
+        }),
+        "buffer_delete" => Ok(CoreCommand::BufferDelete {
+            buffer_name: args.name(1)?,
+            start: args.uint(2)?,
+            end: args.uint(3)?,
+        }),
+        "buffer_append" => Ok(CoreCommand::BufferAppend {
+            buffer_name: args.name(1)?,
+            bytes: args.bytes(2)?,
+        }),
+        "buffer_read_int" => Ok(CoreCommand::BufferReadInt {
+            buffer_name: args.name(1)?,
+            index: args.uint(2)?,
+            int_type: args.int_type(3)?,
+            endianness: args.endianness(4)?,
+        }),
+        "merge_buffers" => Ok(CoreCommand::MergeBuffers {
+            left_buffer_name: args.name(1)?,
+            right_buffer_name: args.name(2)?,
+            new_buffer_name: args.name(3)?.to_string(),
+        }),
+        "pull_out_slice" => Ok(CoreCommand::PullOutSlice {
+            buffer_name: args.name(1)?,
+            new_buffer_name: args.name(2)?.to_string(),
+            start: args.uint(3)?,
+            end: args.uint(4)?,
+        }),
+        "turn_buffer_to_file" => Ok(CoreCommand::TurnBufferToFile {
+            buffer_name: args.name(1)?,
+            new_file_name: args.path(2)?.to_string(),
+            overwrite: args.flag(3)?,
+        }),
+        "save_buffer" => Ok(CoreCommand::SaveBuffer {
+            buffer_name: args.name(1)?,
+            file_name: args.path(2)?,
+        }),
+        "turn_file_to_buffer" => Ok(CoreCommand::TurnFileToBuffer {
+            file_name: args.name(1)?,
+            new_buffer_name: args.name(2)?.to_string(),
+        }),
+        "diff" => Ok(CoreCommand::Diff {
+            left_name: args.name(1)?,
+            right_name: args.name(2)?,
+        }),
+        "checksum" => Ok(CoreCommand::Checksum {
+            algorithm: args.algorithm(1)?,
+            name: args.name(2)?,
+            start: args.uint(3)?,
+            end: args.uint(4)?,
+        }),
+        "checksum_write" => {
+            let algorithm = args.algorithm(1)?;
+            let name = args.name(2)?;
+            let start = args.uint(3)?;
+            let end = args.uint(4)?;
+            let buffer_name = args.name(5)?;
+            let index = args.uint(6)?;
+            // Hashes are written as they are, so only checksums may have type and order of bytes.
+            let layout = match args.get(7) {
+                None => None,
+                Some(_) if algorithm.int_type().is_some() => {
+                    Some((args.int_type(7)?, args.endianness(8)?))
+                }
+                Some(token) => return Err(ParseError::unexpected_argument(command_name, 7, token)),
             };
 
-            Ok(CoreCommand::TurnBufferToFile {
+            Ok(CoreCommand::ChecksumWrite {
+                algorithm,
+                name,
+                start,
+                end,
                 buffer_name,
-                new_file_name: file_name.clone(),
+                index,
+                layout,
             })
         }
-        "turn_file_to_buffer" => {
-            let Some(Token::Word(file_name)) = tokens.get(1) else {
-                return Err(ParseError::unknown_command_template())
-            };
-            let Some(Token::Word(new_buffer_name)) = tokens.get(2) else {
-                return Err(ParseError::unknown_command_template())
-            };
+        "find_bytes" => Ok(CoreCommand::FindBytes {
+            name: args.name(1)?,
+            pattern: args.pattern(2)?,
+        }),
+        "find_all" => Ok(CoreCommand::FindAll {
+            pattern: args.pattern(1)?,
+        }),
+        // The equals sign is checked by `validate`.
+        "let" => Ok(CoreCommand::Let {
+            name: args.name(1)?.to_string(),
+            value: args.integer(3)?,
+        }),
+        "assert" => Ok(CoreCommand::Assert {
+            value: args.integer(1)?,
+            message: args.optional_string(2)?,
+        }),
+        "list" => Ok(CoreCommand::List),
+        "drop" => Ok(CoreCommand::Drop {
+            name: args.name(1)?,
+        }),
+        "rename" => Ok(CoreCommand::Rename {
+            old_name: args.name(1)?,
+            new_name: args.name(2)?,
+        }),
+        "undo" => Ok(CoreCommand::Undo),
+        "redo" => Ok(CoreCommand::Redo),
+        "history" => Ok(CoreCommand::History),
+        "history_set_depth" => Ok(CoreCommand::HistorySetDepth {
+            depth: args.uint(1)?,
+        }),
+        "set" => Ok(CoreCommand::Set {
+            setting: args.setting(1)?,
+        }),
+        name => unreachable!("the command {name} is registered, but isn't parsed"),
+    }
+}
 
-            Ok(CoreCommand::TurnFileToBuffer {
-                file_name,
-                new_buffer_name: new_buffer_name.clone(),
-            })
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::registry::COMMANDS;
+
+    /// The `sample` function returns an argument the parameter of given kind accepts.
+    fn sample(kind: ArgumentKind) -> Token {
+        match kind {
+            ArgumentKind::Name | ArgumentKind::Path => Token::word("a".to_string()),
+            ArgumentKind::UInt
+            | ArgumentKind::Byte
+            | ArgumentKind::Integer
+            | ArgumentKind::Pattern
+            | ArgumentKind::Data
+            | ArgumentKind::OptionValue => Token::UInt(8),
+            ArgumentKind::String => Token::string("a".to_string()),
+            ArgumentKind::IntType => Token::word("u8".to_string()),
+            ArgumentKind::Endianness => Token::word("le".to_string()),
+            ArgumentKind::Algorithm => Token::word("crc32".to_string()),
+            ArgumentKind::Flag(flag) => Token::word(flag.to_string()),
+            ArgumentKind::Encoding => Token::word("ascii".to_string()),
+            ArgumentKind::View => Token::word("hex".to_string()),
+            ArgumentKind::Option => Token::word("columns".to_string()),
+            ArgumentKind::Punct(punct) => Token::Punct(punct),
+        }
+    }
+
+    #[test]
+    fn registered_commands_are_parsed() {
+        for info in COMMANDS.iter() {
+            let mut tokens = vec![Token::word(info.name.to_string())];
+            tokens.extend(
+                info.parameters
+                    .iter()
+                    .filter(|parameter| parameter.presence != Presence::Optional)
+                    .map(|parameter| sample(parameter.kind)),
+            );
+
+            if let Err(e) = parse_tokens(&tokens) {
+                panic!("{} isn't parsed: {}", info.name, e);
+            }
+        }
+    }
+
+    #[test]
+    fn parsed_commands_are_registered() {
+        let source = include_str!("token_parser.rs");
+        let start = source.find("pub fn parse_tokens").unwrap();
+        let end = start + source[start..].find("unreachable!").unwrap();
+
+        let names: Vec<&str> = source[start..end]
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix('"')?.split_once("\" =>"))
+            .map(|(name, _)| name)
+            .collect();
+
+        assert_eq!(names.len(), COMMANDS.len());
+        for name in names {
+            assert!(find_command(name).is_some(), "{name} isn't registered");
         }
-        _ => Err(ParseError::unknown_command_template()),
     }
 }
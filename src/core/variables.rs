@@ -3,24 +3,48 @@ use super::file::File;
 
 use std::collections::HashMap;
 
-enum VariableValue {
+pub enum VariableValue {
     File(File),
     Buffer(Buffer),
+    /// A numeric script variable bound by the `let` command.
+    Integer(i128),
+}
+
+impl VariableValue {
+    /// The `kind` function returns the kind of the value: `buffer`, `file` or `integer`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            VariableValue::File(_) => "file",
+            VariableValue::Buffer(_) => "buffer",
+            VariableValue::Integer(_) => "integer",
+        }
+    }
 }
 
 /// The `Variables` structure stores buffer and files.
 ///
 /// There's following functions:
+/// - `get` returns value with given name whatever it is.
 /// - `get_buffer` returns buffer with given name.
 /// - `get_buffer_mut` returns mutable buffer with given name.
 /// - `get_file` returns file with given name.
 /// - `get_file_mut` returns mutable file with given name.
 /// - `new_buffer` binds given buffer to given name.
+/// - `get_integer` returns value of integer with given name.
 /// - `new_file` binds given file to given name.
+/// - `new_integer` binds given integer to given name.
+/// - `unbind` unbinds given name.
+/// - `rename` binds value with one name to another name.
+/// - `replace` binds given value to given name or unbinds the name.
 ///
-/// There can't be buffer and file with the same names at once.
+/// There can't be buffer, file or integer with the same names at once.
+///
+/// Values unbound or replaced by `new_*`, `unbind` and `rename` aren't deleted at once: they're
+/// released. The core takes them after each command, so the journal can bind them back on undo;
+/// values the journal doesn't keep are deleted then.
 pub struct Variables {
     vars: HashMap<String, VariableValue>,
+    released: Vec<(String, VariableValue)>,
 }
 
 impl Variables {
@@ -28,45 +52,123 @@ impl Variables {
     pub fn new() -> Self {
         Variables {
             vars: HashMap::new(),
+            released: Vec::new(),
         }
     }
 
+    pub fn get(&self, name: &str) -> Option<&VariableValue> {
+        self.vars.get(name)
+    }
+
     pub fn get_buffer(&self, buffer_name: &str) -> Option<&Buffer> {
         self.vars.get(buffer_name).map(|var| match var {
-            VariableValue::File(_) => None,
             VariableValue::Buffer(b) => Some(b),
+            _ => None,
         })?
     }
 
     pub fn get_buffer_mut(&mut self, buffer_name: &str) -> Option<&mut Buffer> {
         self.vars.get_mut(buffer_name).map(|var| match var {
-            VariableValue::File(_) => None,
             VariableValue::Buffer(b) => Some(b),
+            _ => None,
         })?
     }
 
     pub fn get_file(&self, file_name: &str) -> Option<&File> {
         self.vars.get(file_name).map(|var| match var {
             VariableValue::File(f) => Some(f),
-            VariableValue::Buffer(_) => None,
+            _ => None,
         })?
     }
 
     pub fn get_file_mut(&mut self, file_name: &str) -> Option<&mut File> {
         self.vars.get_mut(file_name).map(|var| match var {
             VariableValue::File(f) => Some(f),
-            VariableValue::Buffer(_) => None,
+            _ => None,
         })?
     }
 
-    /// The `new_buffer` function replaces value in `buffer_name` to given buffer. Old value
-    /// deletes.
+    pub fn get_integer(&self, integer_name: &str) -> Option<i128> {
+        match self.vars.get(integer_name)? {
+            VariableValue::Integer(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// The `new_buffer` function replaces value in `buffer_name` to given buffer. Old value is
+    /// released.
     pub fn new_buffer(&mut self, buffer_name: String, buffer: Buffer) {
-        self.vars.insert(buffer_name, VariableValue::Buffer(buffer));
+        self.bind(buffer_name, VariableValue::Buffer(buffer));
     }
 
-    /// The `new_file` function replaces value in `file_name` to given file. Old value deletes.
+    /// The `new_file` function replaces value in `file_name` to given file. Old value is released.
     pub fn new_file(&mut self, file_name: String, file: File) {
-        self.vars.insert(file_name, VariableValue::File(file));
+        self.bind(file_name, VariableValue::File(file));
+    }
+
+    /// The `new_integer` function replaces value in `integer_name` to given integer. Old value is
+    /// released.
+    pub fn new_integer(&mut self, integer_name: String, integer: i128) {
+        self.bind(integer_name, VariableValue::Integer(integer));
+    }
+
+    /// The `unbind` function unbinds given name and releases its value. If there's no variable
+    /// with the name, the function returns `false`.
+    pub fn unbind(&mut self, name: &str) -> bool {
+        match self.vars.remove(name) {
+            Some(value) => {
+                self.released.push((name.to_string(), value));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The `rename` function binds value with the old name to the new name. Old value with the
+    /// new name is released. If there's no variable with the old name, the function does nothing.
+    pub fn rename(&mut self, old_name: &str, new_name: String) {
+        if let Some(value) = self.vars.remove(old_name) {
+            self.bind(new_name, value);
+        }
+    }
+
+    /// The `take_released` function returns values released since the last call.
+    pub fn take_released(&mut self) -> Vec<(String, VariableValue)> {
+        std::mem::take(&mut self.released)
+    }
+
+    fn bind(&mut self, name: String, value: VariableValue) {
+        if let Some(old_value) = self.vars.insert(name.clone(), value) {
+            self.released.push((name, old_value));
+        }
+    }
+
+    /// The `names` function returns names of all variables in alphabetical order.
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.vars.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// The `data_names` function returns names of all buffers and files in alphabetical order.
+    /// Integers are skipped.
+    pub fn data_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .vars
+            .iter()
+            .filter(|(_, var)| !matches!(var, VariableValue::Integer(_)))
+            .map(|(name, _)| name.clone())
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// The `replace` function binds given value to given name and returns old value. If given
+    /// value is `None`, the name is unbound. Old value isn't released.
+    pub fn replace(&mut self, name: String, value: Option<VariableValue>) -> Option<VariableValue> {
+        match value {
+            Some(value) => self.vars.insert(name, value),
+            None => self.vars.remove(&name),
+        }
     }
 }
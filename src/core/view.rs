@@ -0,0 +1,149 @@
+use super::integer::{decode_int, Endianness, IntType};
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+const LITTLE_ENDIAN_SUFFIX: &str = "le";
+const BIG_ENDIAN_SUFFIX: &str = "be";
+
+/// The `FloatType` enumeration contains all types of floating-point numbers that can be shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatType {
+    F32,
+    F64,
+}
+
+impl FloatType {
+    pub fn from_name(name: &str) -> Option<FloatType> {
+        match name {
+            "f32" => Some(FloatType::F32),
+            "f64" => Some(FloatType::F64),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            FloatType::F32 => "f32",
+            FloatType::F64 => "f64",
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        match self {
+            FloatType::F32 => 4,
+            FloatType::F64 => 8,
+        }
+    }
+}
+
+/// The `View` enumeration is the way bytes are shown by `show_buffer` and `show_file`. Bytes are
+/// shown one by one in hexadecimal, binary, octal or decimal form or taken together as integers
+/// or floating-point numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum View {
+    Hex,
+    Bin,
+    Oct,
+    Dec,
+    Int(IntType, Endianness),
+    Float(FloatType, Endianness),
+}
+
+impl View {
+    /// The `from_name` function returns the view with given name: `hex`, `bin`, `oct`, `dec` or
+    /// a type of integer or floating-point number wider than a byte followed by the byte order,
+    /// for example `u32le` or `f64be`. If there's no view with the name, the function returns
+    /// `None`.
+    pub fn from_name(name: &str) -> Option<View> {
+        match name {
+            "hex" => return Some(View::Hex),
+            "bin" => return Some(View::Bin),
+            "oct" => return Some(View::Oct),
+            "dec" => return Some(View::Dec),
+            _ => {}
+        }
+
+        let (type_name, endianness) =
+            if let Some(type_name) = name.strip_suffix(LITTLE_ENDIAN_SUFFIX) {
+                (type_name, Endianness::Little)
+            } else {
+                (name.strip_suffix(BIG_ENDIAN_SUFFIX)?, Endianness::Big)
+            };
+
+        if let Some(float_type) = FloatType::from_name(type_name) {
+            return Some(View::Float(float_type, endianness));
+        }
+
+        IntType::from_name(type_name)
+            .filter(|int_type| int_type.width() > 1)
+            .map(|int_type| View::Int(int_type, endianness))
+    }
+
+    /// The `width` function returns count of bytes shown in one cell.
+    pub fn width(&self) -> usize {
+        match self {
+            View::Hex | View::Bin | View::Oct | View::Dec => 1,
+            View::Int(int_type, _) => int_type.width(),
+            View::Float(float_type, _) => float_type.width(),
+        }
+    }
+
+    /// The `format` function returns the cell showing given bytes.
+    ///
+    /// Accepted guarantees:
+    /// - `bytes.len() == self.width()`.
+    pub fn format(&self, bytes: &[u8]) -> String {
+        debug_assert_eq!(bytes.len(), self.width());
+
+        match *self {
+            View::Hex => format!("{:02x}", bytes[0]),
+            View::Bin => format!("{:08b}", bytes[0]),
+            View::Oct => format!("{:03o}", bytes[0]),
+            View::Dec => bytes[0].to_string(),
+            View::Int(int_type, endianness) => decode_int(bytes, int_type, endianness).to_string(),
+            View::Float(float_type, endianness) => format_float(bytes, float_type, endianness),
+        }
+    }
+}
+
+impl Display for View {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            View::Hex => write!(f, "hex"),
+            View::Bin => write!(f, "bin"),
+            View::Oct => write!(f, "oct"),
+            View::Dec => write!(f, "dec"),
+            View::Int(int_type, endianness) => write!(f, "{}{}", int_type, endianness),
+            View::Float(float_type, endianness) => {
+                write!(f, "{}{}", float_type.name(), endianness)
+            }
+        }
+    }
+}
+
+/// The `format_float` function writes the floating-point number. Numbers that are too large or
+/// too small for the plain notation are written in the scientific one.
+fn format_float(bytes: &[u8], float_type: FloatType, endianness: Endianness) -> String {
+    let mut le_bytes = bytes.to_vec();
+    if endianness == Endianness::Big {
+        le_bytes.reverse();
+    }
+
+    let (value, plain, scientific) = match float_type {
+        FloatType::F32 => {
+            let value = f32::from_le_bytes(le_bytes.try_into().unwrap());
+            (value as f64, value.to_string(), format!("{value:e}"))
+        }
+        FloatType::F64 => {
+            let value = f64::from_le_bytes(le_bytes.try_into().unwrap());
+            (value, value.to_string(), format!("{value:e}"))
+        }
+    };
+
+    let magnitude = value.abs();
+    if value == 0.0 || !value.is_finite() || (1e-4..1e7).contains(&magnitude) {
+        plain
+    } else {
+        scientific
+    }
+}
@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+
+/// The `Arguments` structure keeps arguments the script is launched with. Inside the script,
+/// positional arguments are substituted for `$1`, `$2`, ... and named ones for `$<name>`.
+#[derive(Debug, Default, Clone)]
+pub struct Arguments {
+    positional: Vec<String>,
+    named: HashMap<String, String>,
+}
+
+impl Arguments {
+    pub fn new(positional: Vec<String>, named: HashMap<String, String>) -> Self {
+        Arguments { positional, named }
+    }
+
+    /// The `get` function returns value of the argument. A name of digits is the number of
+    /// positional argument counting from one.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        if name.chars().all(|ch| ch.is_ascii_digit()) {
+            let number = name.parse::<usize>().ok()?;
+            return self
+                .positional
+                .get(number.checked_sub(1)?)
+                .map(String::as_str);
+        }
+
+        self.named.get(name).map(String::as_str)
+    }
+
+    /// The `is_name` function checks whether the string can be a name of named argument: it
+    /// consists of latin letters, digits and underscores and doesn't start with a digit.
+    pub fn is_name(name: &str) -> bool {
+        let mut chars = name.chars();
+
+        matches!(chars.next(), Some('A'..='Z' | 'a'..='z' | '_'))
+            && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+    }
+}
@@ -0,0 +1,145 @@
+use crate::core::{find_command, ArgumentKind, CommandInfo, COMMANDS, OPTIONS};
+use crate::terminal::rcommand::RUN_COMMANDS;
+
+use rustyline::completion::{Completer, FilenameCompleter, Pair};
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::{Context, Helper, Result as RlResult};
+
+const HELP_WORD: &str = "help";
+const BLOCK_WORDS: [&str; 3] = ["for", "if", "def"];
+
+/// The `CompletionHelper` structure completes words of command being entered in the terminal.
+/// What's completed depends on the position of the word:
+/// - The first word is completed by names of commands.
+/// - Paths of `open_file`, `open_file_rw`, `create_file`, `include`, etc. are completed by paths
+///   of the file system.
+/// - Other names are completed by names of buffers, files and integer variables.
+pub struct CompletionHelper {
+    /// Names of built-in commands, blocks and user-defined commands in alphabetical order.
+    commands: Vec<String>,
+    /// Names of buffers, files and integer variables.
+    names: Vec<String>,
+    filename_completer: FilenameCompleter,
+}
+
+impl CompletionHelper {
+    pub fn new() -> Self {
+        CompletionHelper {
+            commands: builtin_commands(),
+            names: Vec::new(),
+            filename_completer: FilenameCompleter::new(),
+        }
+    }
+
+    /// The `set_names` function replaces names of user-defined commands and variables.
+    pub fn set_names(&mut self, user_commands: Vec<String>, names: Vec<String>) {
+        let mut commands = builtin_commands();
+        commands.extend(user_commands);
+        commands.sort();
+        commands.dedup();
+
+        self.commands = commands;
+        self.names = names;
+    }
+
+    /// The `candidates` function returns words that can be written after given words of command.
+    /// If the word is a path, the function returns `None`.
+    fn candidates(&self, words: &[&str]) -> Option<Vec<String>> {
+        let Some((command, arguments)) = words.split_first() else {
+            return Some(self.commands.clone());
+        };
+
+        if *command == HELP_WORD {
+            return Some(self.commands.clone());
+        }
+
+        let kind = find_info(command)
+            .and_then(|info| info.parameter(arguments.len() + 1))
+            .map(|parameter| parameter.kind);
+
+        match kind {
+            Some(ArgumentKind::Path) => None,
+            Some(ArgumentKind::Flag(flag)) => Some(vec![flag.to_string()]),
+            Some(ArgumentKind::Option) => Some(OPTIONS.map(str::to_string).to_vec()),
+            // Integers may be written by integer variables.
+            Some(
+                ArgumentKind::Name
+                | ArgumentKind::UInt
+                | ArgumentKind::Byte
+                | ArgumentKind::Integer
+                | ArgumentKind::Data,
+            )
+            | None => Some(self.names.clone()),
+            Some(_) => Some(Vec::new()),
+        }
+    }
+}
+
+impl Default for CompletionHelper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The `builtin_commands` function returns names of commands of the runner and the core and words
+/// starting blocks in alphabetical order.
+fn builtin_commands() -> Vec<String> {
+    let mut commands: Vec<String> = RUN_COMMANDS
+        .iter()
+        .chain(COMMANDS.iter())
+        .map(|info| info.name.to_string())
+        .chain(BLOCK_WORDS.iter().map(|word| word.to_string()))
+        .collect();
+    commands.sort();
+    commands
+}
+
+/// The `find_info` function returns description of the command of the runner or the core.
+fn find_info(name: &str) -> Option<&'static CommandInfo> {
+    RUN_COMMANDS
+        .iter()
+        .find(|info| info.name == name)
+        .or_else(|| find_command(name))
+}
+
+impl Completer for CompletionHelper {
+    type Candidate = Pair;
+
+    fn complete(&self, line: &str, pos: usize, _ctx: &Context<'_>) -> RlResult<(usize, Vec<Pair>)> {
+        let before = &line[..pos];
+        let start = before
+            .char_indices()
+            .rev()
+            .find(|(_, ch)| ch.is_whitespace())
+            .map_or(0, |(index, ch)| index + ch.len_utf8());
+
+        let words: Vec<&str> = before[..start].split_whitespace().collect();
+        let Some(candidates) = self.candidates(&words) else {
+            return self.filename_completer.complete_path(line, pos);
+        };
+
+        let word = &before[start..];
+        let pairs = candidates
+            .into_iter()
+            .filter(|candidate| candidate.starts_with(word))
+            .map(|candidate| Pair {
+                display: candidate.clone(),
+                replacement: candidate,
+            })
+            .collect();
+
+        Ok((start, pairs))
+    }
+}
+
+impl Hinter for CompletionHelper {
+    type Hint = String;
+}
+
+impl Highlighter for CompletionHelper {}
+
+impl Validator for CompletionHelper {}
+
+impl Helper for CompletionHelper {}
@@ -33,6 +33,7 @@ impl Iterator for Liner {
 }
 
 pub struct FileReader {
+    path: String,
     // This is instead of `in_stream`.
     liner: Liner,
     out_stream: Stdout,
@@ -46,6 +47,7 @@ impl FileReader {
         file.read_to_string(&mut buf)?;
 
         Ok(FileReader {
+            path: path.to_string(),
             liner: Liner::new(buf),
             out_stream: Self::make_out_stream(),
             error_stream: Self::make_error_stream(),
@@ -54,7 +56,7 @@ impl FileReader {
 
     /// The `write_raw` function returns result of writing string to terminal.
     fn write_raw(&mut self, string: &str) -> IoResult<()> {
-        self.out_stream.write(string.as_bytes())?;
+        self.out_stream.write_all(string.as_bytes())?;
         self.out_stream.flush()?;
 
         Ok(())
@@ -85,6 +87,10 @@ impl Commander for FileReader {
         false
     }
 
+    fn source_name(&self) -> String {
+        self.path.clone()
+    }
+
     fn read_command(&mut self) -> Option<String> {
         self.liner.next()
     }
@@ -1,3 +1,5 @@
+mod completion;
+
 pub mod file;
 pub mod terminal;
 
@@ -11,6 +13,12 @@ pub use terminal::Terminal;
 /// the `read_command` function has to be replaced it to empty string.
 pub trait Commander {
     fn is_terminal() -> bool;
+    /// The `source_name` function returns name of commands' source used in error messages. For
+    /// a file, that's its path.
+    fn source_name(&self) -> String;
     fn read_command(&mut self) -> Option<String>;
     fn write_result(&mut self, result: String);
+    /// The `set_completion_names` function gives the commander names it may complete while a
+    /// command is being entered: user-defined commands and variables. By default, it does nothing.
+    fn set_completion_names(&mut self, _commands: Vec<String>, _names: Vec<String>) {}
 }
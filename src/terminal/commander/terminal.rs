@@ -1,24 +1,61 @@
+use super::completion::CompletionHelper;
 use super::Commander;
 
+use rustyline::config::{CompletionType, Config};
+use rustyline::error::ReadlineError;
+use rustyline::history::FileHistory;
+use rustyline::Editor;
+
+use std::env;
 use std::io::{
     stderr, stdin, stdout, IsTerminal, Lines, Result as IoResult, Stderr, StdinLock, Stdout, Write,
 };
+use std::path::PathBuf;
 
 const PROMPT: &str = ">>> ";
 
+const SOURCE_NAME: &str = "<stdin>";
+
 const REGULAR_COMMENT_START: &str = "//";
 
+const HISTORY_FILE_NAME: &str = ".fmodifier_history";
+const HISTORY_SIZE: usize = 1000;
+
 const EXIT_ERROR_CODE: i32 = 1;
 
 const IO_ERROR_MESSAGE: &str = "The I/O system has failed";
 const STDIN_NOT_TERMINAL_ERROR_MESSAGE: &str = "Stdin must be terminal, sir.";
 
 type Liner = Lines<StdinLock<'static>>;
+type LineEditor = Editor<CompletionHelper, FileHistory>;
+
+/// The `Input` enumeration is the source of lines of the terminal. If stdin is an interactive
+/// terminal, lines are read by the line editor supporting history and completion. Otherwise, they
+/// are just read line by line.
+enum Input {
+    Editor(Box<LineEditor>),
+    Lines(Liner),
+}
 
-/// The `clear_comments` function clears all comments in given string.
+/// The `clear_comments` function clears all comments in given string. A comment starts with `//`
+/// outside of string literals, so `"http://"` isn't cut.
 fn clear_comments(string: &str) -> String {
-    let Some(comment_start_index) = string.find("//") else { return string.to_string() };
-    string[..comment_start_index].to_string()
+    let mut in_quotes = false;
+    let mut escaped = false;
+
+    for (index, ch) in string.char_indices() {
+        match ch {
+            _ if escaped => escaped = false,
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            _ if !in_quotes && string[index..].starts_with(REGULAR_COMMENT_START) => {
+                return string[..index].to_string();
+            }
+            _ => {}
+        }
+    }
+
+    string.to_string()
 }
 
 /// The `Terminal` structure provides function for reading and writing to terminal. It provides
@@ -40,21 +77,32 @@ fn clear_comments(string: &str) -> String {
 /// core.execute(command)?;
 /// ```
 pub struct Terminal {
-    liner: Liner,
+    input: Input,
+    history_path: Option<PathBuf>,
     out_stream: Stdout,
     error_stream: Stderr,
 
     interacitve_mode: bool,
 }
 
+impl Default for Terminal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Terminal {
     pub fn new() -> Self {
+        let interacitve_mode = Self::is_interactive_mode();
+        let history_path = Self::make_history_path();
+
         Terminal {
-            liner: Self::make_in_lines(),
+            input: Self::make_input(interacitve_mode, history_path.as_ref()),
+            history_path,
             out_stream: Self::make_out_stream(),
             error_stream: Self::make_error_stream(),
 
-            interacitve_mode: Self::is_interactive_mode(),
+            interacitve_mode,
         }
     }
 
@@ -66,6 +114,46 @@ impl Terminal {
         stdin().lines()
     }
 
+    /// The `make_history_path` function returns path of the file keeping history of entered
+    /// commands. The file is placed in the home directory.
+    fn make_history_path() -> Option<PathBuf> {
+        env::var_os("HOME").map(|home| PathBuf::from(home).join(HISTORY_FILE_NAME))
+    }
+
+    /// The `make_editor` function returns the line editor with history loaded from the file. If the
+    /// file doesn't exist yet, history is empty.
+    fn make_editor(history_path: Option<&PathBuf>) -> Option<LineEditor> {
+        let config = Config::builder()
+            .auto_add_history(false)
+            .max_history_size(HISTORY_SIZE)
+            .ok()?
+            .history_ignore_dups(true)
+            .ok()?
+            .completion_type(CompletionType::List)
+            .build();
+
+        let mut editor = LineEditor::with_config(config).ok()?;
+        editor.set_helper(Some(CompletionHelper::new()));
+        if let Some(path) = history_path {
+            let _ = editor.load_history(path);
+        }
+
+        Some(editor)
+    }
+
+    /// The `make_input` function returns the line editor if stdin is an interactive terminal and
+    /// the editor could be created. Otherwise, it returns plain lines of stdin.
+    fn make_input(interactive_mode: bool, history_path: Option<&PathBuf>) -> Input {
+        if !interactive_mode {
+            return Input::Lines(Self::make_in_lines());
+        }
+
+        match Self::make_editor(history_path) {
+            Some(editor) => Input::Editor(Box::new(editor)),
+            None => Input::Lines(Self::make_in_lines()),
+        }
+    }
+
     fn make_out_stream() -> Stdout {
         stdout()
     }
@@ -75,7 +163,7 @@ impl Terminal {
     }
 
     fn print_prompt(&mut self) -> IoResult<()> {
-        self.out_stream.write(PROMPT.as_bytes())?;
+        self.out_stream.write_all(PROMPT.as_bytes())?;
         self.out_stream.flush()?;
 
         Ok(())
@@ -83,17 +171,37 @@ impl Terminal {
 
     /// The `read_line_raw` function returns result of getting string from terminal. The function
     /// can be blocked.
+    ///
+    /// Ctrl-C clears the line being entered, Ctrl-D finishes reading.
     fn read_line_raw(&mut self) -> Option<String> {
-        if self.interacitve_mode {
+        if matches!(self.input, Input::Lines(_)) && self.interacitve_mode {
             self.print_prompt().ok()?;
         }
 
-        self.liner.next()?.ok()
+        let editor = match &mut self.input {
+            Input::Editor(editor) => editor,
+            Input::Lines(liner) => return liner.next()?.ok(),
+        };
+
+        let line = match editor.readline(PROMPT) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => return Some(String::new()),
+            Err(_) => return None,
+        };
+
+        if !line.trim().is_empty() && editor.add_history_entry(line.as_str()).unwrap_or(false) {
+            // History is appended after each command, so it isn't lost if the program is killed.
+            if let Some(path) = &self.history_path {
+                let _ = editor.append_history(path);
+            }
+        }
+
+        Some(line)
     }
 
     /// The `write_raw` function returns result of writing string to terminal.
     fn write_raw(&mut self, string: &str) -> IoResult<()> {
-        self.out_stream.write(string.as_bytes())?;
+        self.out_stream.write_all(string.as_bytes())?;
         self.out_stream.flush()?;
 
         Ok(())
@@ -112,6 +220,10 @@ impl Commander for Terminal {
         true
     }
 
+    fn source_name(&self) -> String {
+        SOURCE_NAME.to_string()
+    }
+
     fn read_command(&mut self) -> Option<String> {
         self.read_line_raw().map(|string| clear_comments(&string))
     }
@@ -120,4 +232,12 @@ impl Commander for Terminal {
         let io_result = self.write_raw(&result);
         self.parse_io_result(io_result);
     }
+
+    fn set_completion_names(&mut self, commands: Vec<String>, names: Vec<String>) {
+        if let Input::Editor(editor) = &mut self.input {
+            if let Some(helper) = editor.helper_mut() {
+                helper.set_names(commands, names);
+            }
+        }
+    }
 }
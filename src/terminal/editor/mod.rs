@@ -0,0 +1,70 @@
+mod screen;
+mod state;
+
+use screen::{draw, layout};
+use state::{Action, Editor};
+
+use crossterm::cursor::{Hide, Show};
+use crossterm::event::{read, Event, KeyEventKind};
+use crossterm::execute;
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, size, EnterAlternateScreen, LeaveAlternateScreen,
+};
+
+use std::io::{stdout, Error as IoError, IsTerminal, Result as IoResult};
+
+/// The `ScreenGuard` structure switches the terminal to the full-screen mode and back when it's
+/// dropped, even if the editor fails.
+struct ScreenGuard;
+
+impl ScreenGuard {
+    fn enter() -> IoResult<Self> {
+        enable_raw_mode()?;
+        if let Err(e) = execute!(stdout(), EnterAlternateScreen, Hide) {
+            let _ = disable_raw_mode();
+            return Err(e);
+        }
+
+        Ok(ScreenGuard)
+    }
+}
+
+impl Drop for ScreenGuard {
+    fn drop(&mut self) {
+        let _ = execute!(stdout(), Show, LeaveAlternateScreen);
+        let _ = disable_raw_mode();
+    }
+}
+
+/// The `edit` function opens the full-screen hex editor over given bytes. The title is shown in
+/// the top line. If the editor is closed with saving, the function returns the edited bytes;
+/// otherwise, it returns `None`.
+///
+/// Bytes are overwritten, so their count stays the same.
+pub fn edit(title: &str, bytes: Vec<u8>) -> IoResult<Option<Vec<u8>>> {
+    if !stdout().is_terminal() {
+        return Err(IoError::other("the editor needs a terminal"));
+    }
+
+    let mut editor = Editor::new(title, bytes);
+    let _guard = ScreenGuard::enter()?;
+    let mut out = stdout();
+
+    loop {
+        let (width, height) = size()?;
+        let (columns, page_rows) = layout(width, height);
+        editor.set_layout(columns, page_rows);
+        draw(&mut out, &editor, width, height)?;
+
+        let Event::Key(key) = read()? else {
+            continue;
+        };
+        if key.kind == KeyEventKind::Release {
+            continue;
+        }
+
+        if let Action::Close { commit } = editor.handle_key(key) {
+            return Ok(commit.then(|| editor.into_bytes()));
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use super::state::{Editor, Pane};
+
+use crossterm::cursor::MoveTo;
+use crossterm::queue;
+use crossterm::style::{Attribute, Color, Print, ResetColor, SetAttribute, SetForegroundColor};
+use crossterm::terminal::{Clear, ClearType};
+
+use std::io::{Result as IoResult, Write};
+
+const SEPARATOR: &str = " ┃ ";
+const KEYS_HELP: &str = "^S save and close  ^Q quit  ^G go to  ^F search  ^N next  Tab hex/ASCII";
+const MODIFIED_COLOR: Color = Color::Yellow;
+/// Lines that aren't rows of bytes: the title, the status line and the bottom line.
+const SERVICE_LINES: usize = 3;
+
+/// The `layout` function returns count of bytes in a row and count of rows fitting the screen of
+/// given size.
+pub fn layout(width: u16, height: u16) -> (usize, usize) {
+    // The offset, two separators and four characters for each byte.
+    let columns = match width as usize >= 8 + 2 * SEPARATOR.chars().count() + 16 * 4 {
+        true => 16,
+        false => 8,
+    };
+
+    (columns, (height as usize).saturating_sub(SERVICE_LINES))
+}
+
+/// The `draw` function draws the editor on the whole screen of given size.
+pub fn draw<W: Write>(out: &mut W, editor: &Editor, width: u16, height: u16) -> IoResult<()> {
+    let (_, page_rows) = layout(width, height);
+
+    queue!(out, MoveTo(0, 0), Clear(ClearType::All))?;
+    draw_inverted(
+        out,
+        &format!(" {} - {} bytes", editor.title(), editor.bytes().len()),
+        width,
+    )?;
+
+    for line in 0..page_rows {
+        let row = editor.top_row() + line;
+        queue!(out, MoveTo(0, line as u16 + 1))?;
+        if row * editor.columns() >= editor.bytes().len().max(1) {
+            break;
+        }
+        draw_row(out, editor, row)?;
+    }
+
+    queue!(out, MoveTo(0, height.saturating_sub(2)))?;
+    draw_inverted(out, &status(editor), width)?;
+
+    queue!(out, MoveTo(0, height.saturating_sub(1)))?;
+    match (editor.prompt(), editor.message()) {
+        (Some((prompt, input)), _) => queue!(out, Print(prompt.question()), Print(input))?,
+        (None, Some(message)) => queue!(out, Print(message))?,
+        (None, None) => queue!(out, Print(KEYS_HELP))?,
+    }
+
+    out.flush()
+}
+
+/// The `draw_row` function draws the offset of the row, its bytes in hexadecimal form and its
+/// characters. The byte under the cursor is inverted in the active pane and underlined in the
+/// other one; changed bytes are colored.
+fn draw_row<W: Write>(out: &mut W, editor: &Editor, row: usize) -> IoResult<()> {
+    let start = row * editor.columns();
+    let indexes = start..start + editor.columns();
+
+    queue!(out, Print(format!("{start:08x}")), Print(SEPARATOR))?;
+    for index in indexes.clone() {
+        let cell = editor.bytes().get(index).map(|byte| format!("{byte:02x}"));
+        draw_cell(out, editor, index, cell.as_deref(), Pane::Hex, "  ")?;
+        queue!(out, Print(" "))?;
+    }
+
+    queue!(out, Print(&SEPARATOR[1..]))?;
+    for index in indexes {
+        let cell = editor.bytes().get(index).map(|byte| match byte {
+            0x20..=0x7E => (*byte as char).to_string(),
+            _ => ".".to_string(),
+        });
+        draw_cell(out, editor, index, cell.as_deref(), Pane::Ascii, " ")?;
+    }
+
+    Ok(())
+}
+
+fn draw_cell<W: Write>(
+    out: &mut W,
+    editor: &Editor,
+    index: usize,
+    cell: Option<&str>,
+    pane: Pane,
+    empty: &str,
+) -> IoResult<()> {
+    let Some(cell) = cell else {
+        return queue!(out, Print(empty));
+    };
+
+    if editor.is_byte_modified(index) {
+        queue!(out, SetForegroundColor(MODIFIED_COLOR))?;
+    }
+    if index == editor.cursor() {
+        let attribute = match editor.pane() == pane {
+            true => Attribute::Reverse,
+            false => Attribute::Underlined,
+        };
+        queue!(out, SetAttribute(attribute))?;
+    }
+
+    queue!(out, Print(cell), SetAttribute(Attribute::Reset), ResetColor)
+}
+
+/// The `status` function returns the status line: the offset of the cursor, the byte under it,
+/// the active pane and whether there're changes.
+fn status(editor: &Editor) -> String {
+    let cursor = editor.cursor();
+    let mut status = format!(" Offset: {cursor:#x} ({cursor})");
+
+    if let Some(byte) = editor.bytes().get(cursor) {
+        status.push_str(&format!("  Byte: {byte:#04x} ({byte})"));
+    }
+    status.push_str(match editor.pane() {
+        Pane::Hex => "  Hex",
+        Pane::Ascii => "  ASCII",
+    });
+    if editor.is_modified() {
+        status.push_str("  Modified");
+    }
+
+    status
+}
+
+/// The `draw_inverted` function draws the line with inverted colors over the whole width.
+fn draw_inverted<W: Write>(out: &mut W, line: &str, width: u16) -> IoResult<()> {
+    queue!(
+        out,
+        SetAttribute(Attribute::Reverse),
+        Print(format!("{line:<width$}", width = width as usize)),
+        SetAttribute(Attribute::Reset)
+    )
+}
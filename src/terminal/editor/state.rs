@@ -0,0 +1,471 @@
+use crate::core::Token;
+use crate::terminal::arguments::Arguments;
+use crate::terminal::tokenizer::tokenize;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+const SEARCH_HINT: &str = "Write bytes like 0x4D 90 ?? or \"text\".";
+
+/// The `Pane` enumeration is the part of the editor the cursor is in: bytes are typed as
+/// hexadecimal digits in the hex pane and as characters in the ASCII pane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pane {
+    Hex,
+    Ascii,
+}
+
+/// The `Prompt` enumeration is a question the editor asks in the bottom line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Prompt {
+    Goto,
+    Search,
+    /// Whether changes are discarded on closing.
+    Discard,
+}
+
+impl Prompt {
+    pub fn question(&self) -> &'static str {
+        match self {
+            Prompt::Goto => "Go to offset: ",
+            Prompt::Search => "Search: ",
+            Prompt::Discard => "Discard changes? (y/n) ",
+        }
+    }
+}
+
+/// The `Action` enumeration is what the editor has to do after a key is pressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Continue,
+    /// Close the editor. If `commit` is set, the edited bytes are kept.
+    Close {
+        commit: bool,
+    },
+}
+
+/// The `Editor` structure is the state of the hex editor: edited bytes, the cursor and the
+/// scrolling. It doesn't know how it's drawn: the screen takes the state and draws it.
+pub struct Editor {
+    title: String,
+    bytes: Vec<u8>,
+    original: Vec<u8>,
+    cursor: usize,
+    /// If it's set, the next hexadecimal digit is the low half of the byte under the cursor.
+    low_nibble: bool,
+    pane: Pane,
+    /// The first row shown on the screen.
+    top_row: usize,
+    /// Count of bytes in a row.
+    columns: usize,
+    /// Count of rows fitting the screen.
+    page_rows: usize,
+    prompt: Option<(Prompt, String)>,
+    message: Option<String>,
+    /// The last searched pattern. `None` in the pattern matches any byte.
+    pattern: Vec<Option<u8>>,
+}
+
+impl Editor {
+    pub fn new(title: &str, bytes: Vec<u8>) -> Self {
+        Editor {
+            title: title.to_string(),
+            original: bytes.clone(),
+            bytes,
+            cursor: 0,
+            low_nibble: false,
+            pane: Pane::Hex,
+            top_row: 0,
+            columns: 16,
+            page_rows: 1,
+            prompt: None,
+            message: None,
+            pattern: Vec::new(),
+        }
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn pane(&self) -> Pane {
+        self.pane
+    }
+
+    pub fn top_row(&self) -> usize {
+        self.top_row
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn prompt(&self) -> Option<(Prompt, &str)> {
+        self.prompt
+            .as_ref()
+            .map(|(prompt, input)| (*prompt, input.as_str()))
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// The `is_byte_modified` function tells if the byte at given index differs from the original one.
+    pub fn is_byte_modified(&self, index: usize) -> bool {
+        self.bytes[index] != self.original[index]
+    }
+
+    pub fn is_modified(&self) -> bool {
+        self.bytes != self.original
+    }
+
+    /// The `set_layout` function sets count of bytes in a row and count of rows fitting the
+    /// screen. It's called before each drawing, since the screen can be resized.
+    pub fn set_layout(&mut self, columns: usize, page_rows: usize) {
+        self.columns = columns.max(1);
+        self.page_rows = page_rows.max(1);
+        self.scroll();
+    }
+
+    /// The `handle_key` function changes the state according to the pressed key.
+    pub fn handle_key(&mut self, key: KeyEvent) -> Action {
+        if self.prompt.is_some() {
+            return self.handle_prompt_key(key);
+        }
+        self.message = None;
+
+        let control = key.modifiers.contains(KeyModifiers::CONTROL);
+        let page = self.columns * self.page_rows;
+        let row_start = self.cursor - self.cursor % self.columns;
+
+        match key.code {
+            KeyCode::Char('s') if control => return Action::Close { commit: true },
+            KeyCode::Char('q' | 'c') if control => return self.close(),
+            KeyCode::Esc => return self.close(),
+            KeyCode::Char('g') if control => self.prompt = Some((Prompt::Goto, String::new())),
+            KeyCode::Char('f') if control => self.prompt = Some((Prompt::Search, String::new())),
+            KeyCode::Char('n') if control => self.search_next(),
+            KeyCode::F(3) => self.search_next(),
+            KeyCode::Tab | KeyCode::BackTab => {
+                self.pane = match self.pane {
+                    Pane::Hex => Pane::Ascii,
+                    Pane::Ascii => Pane::Hex,
+                };
+                self.low_nibble = false;
+            }
+            KeyCode::Left => self.move_to(self.cursor.saturating_sub(1)),
+            KeyCode::Right => self.move_to(self.cursor.saturating_add(1)),
+            KeyCode::Up => self.move_to(self.cursor.saturating_sub(self.columns)),
+            KeyCode::Down => self.move_to(self.cursor.saturating_add(self.columns)),
+            KeyCode::PageUp => self.move_to(self.cursor.saturating_sub(page)),
+            KeyCode::PageDown => self.move_to(self.cursor.saturating_add(page)),
+            KeyCode::Home if control => self.move_to(0),
+            KeyCode::End if control => self.move_to(usize::MAX),
+            KeyCode::Home => self.move_to(row_start),
+            KeyCode::End => self.move_to(row_start + self.columns - 1),
+            KeyCode::Char(ch) if !control => self.type_char(ch),
+            _ => {}
+        }
+
+        Action::Continue
+    }
+
+    /// The `close` function closes the editor or asks whether changes are discarded if there're
+    /// any.
+    fn close(&mut self) -> Action {
+        if self.is_modified() {
+            self.prompt = Some((Prompt::Discard, String::new()));
+            Action::Continue
+        } else {
+            Action::Close { commit: false }
+        }
+    }
+
+    fn handle_prompt_key(&mut self, key: KeyEvent) -> Action {
+        let Some((prompt, input)) = &mut self.prompt else {
+            return Action::Continue;
+        };
+
+        if *prompt == Prompt::Discard {
+            let discard = matches!(key.code, KeyCode::Char('y' | 'Y'));
+            self.prompt = None;
+
+            return match discard {
+                true => Action::Close { commit: false },
+                false => Action::Continue,
+            };
+        }
+
+        match key.code {
+            KeyCode::Char(ch) => input.push(ch),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Esc => self.prompt = None,
+            KeyCode::Enter => {
+                let (prompt, input) = self.prompt.take().unwrap();
+                match prompt {
+                    Prompt::Goto => self.goto(&input),
+                    Prompt::Search => self.search(&input),
+                    Prompt::Discard => {}
+                }
+            }
+            _ => {}
+        }
+
+        Action::Continue
+    }
+
+    /// The `move_to` function moves the cursor to given index. An index past the end moves the
+    /// cursor to the last byte.
+    fn move_to(&mut self, index: usize) {
+        self.cursor = index.min(self.bytes.len().saturating_sub(1));
+        self.low_nibble = false;
+        self.scroll();
+    }
+
+    /// The `scroll` function scrolls the screen so the cursor is seen.
+    fn scroll(&mut self) {
+        let row = self.cursor / self.columns;
+
+        if row < self.top_row {
+            self.top_row = row;
+        } else if row >= self.top_row + self.page_rows {
+            self.top_row = row + 1 - self.page_rows;
+        }
+    }
+
+    /// The `type_char` function overwrites the byte under the cursor by typed character: a half
+    /// of byte in the hex pane, a whole byte in the ASCII pane.
+    fn type_char(&mut self, ch: char) {
+        if self.bytes.is_empty() {
+            return;
+        }
+
+        match self.pane {
+            Pane::Hex => {
+                let Some(digit) = ch.to_digit(16) else {
+                    return;
+                };
+                let byte = &mut self.bytes[self.cursor];
+
+                if self.low_nibble {
+                    *byte = (*byte & 0xF0) | digit as u8;
+                    self.move_to(self.cursor + 1);
+                } else {
+                    *byte = (*byte & 0x0F) | (digit as u8) << 4;
+                    self.low_nibble = true;
+                }
+            }
+            Pane::Ascii => {
+                if !(' '..='~').contains(&ch) {
+                    return;
+                }
+                self.bytes[self.cursor] = ch as u8;
+                self.move_to(self.cursor + 1);
+            }
+        }
+    }
+
+    /// The `goto` function moves the cursor to the offset written in decimal or hexadecimal
+    /// form.
+    fn goto(&mut self, input: &str) {
+        match tokenize(input, &Arguments::default()).as_deref() {
+            Ok([Token::UInt(offset)]) if *offset < self.bytes.len() => self.move_to(*offset),
+            Ok([Token::UInt(offset)]) => {
+                self.message = Some(format!(
+                    "The offset {offset} is out of the buffer of size {}.",
+                    self.bytes.len()
+                ))
+            }
+            _ => self.message = Some(format!("'{input}' isn't an offset.")),
+        }
+    }
+
+    /// The `search` function remembers the pattern and moves the cursor to its first occurrence
+    /// starting from the cursor.
+    fn search(&mut self, input: &str) {
+        match parse_pattern(input) {
+            Some(pattern) => {
+                self.pattern = pattern;
+                self.find_from(self.cursor);
+            }
+            None => self.message = Some(SEARCH_HINT.to_string()),
+        }
+    }
+
+    /// The `search_next` function moves the cursor to the next occurrence of the last pattern.
+    fn search_next(&mut self) {
+        match self.pattern.is_empty() {
+            true => self.message = Some("Nothing is searched yet.".to_string()),
+            false => self.find_from(self.cursor + 1),
+        }
+    }
+
+    /// The `find_from` function moves the cursor to the occurrence of the pattern starting with
+    /// given index or later. The search wraps around the end.
+    fn find_from(&mut self, start: usize) {
+        let len = self.bytes.len();
+        let matches = |index: usize| {
+            len - index >= self.pattern.len()
+                && self.bytes[index..]
+                    .iter()
+                    .zip(&self.pattern)
+                    .all(|(byte, expected)| expected.is_none_or(|expected| *byte == expected))
+        };
+
+        let found = (0..len)
+            .map(|offset| (start + offset) % len)
+            .find(|index| matches(*index));
+
+        match found {
+            Some(index) => {
+                if index < start {
+                    self.message = Some("The search wrapped around the end.".to_string());
+                }
+                self.move_to(index);
+            }
+            None => self.message = Some("The pattern isn't found.".to_string()),
+        }
+    }
+}
+
+/// The `parse_pattern` function reads pattern of search written like arguments of `find_bytes`:
+/// bytes, wildcards `??` and ASCII strings.
+fn parse_pattern(input: &str) -> Option<Vec<Option<u8>>> {
+    let tokens = tokenize(input, &Arguments::default()).ok()?;
+    let mut pattern = Vec::new();
+
+    for token in &tokens {
+        match token {
+            Token::UInt(byte) if *byte <= u8::MAX as usize => pattern.push(Some(*byte as u8)),
+            Token::Wildcard => pattern.push(None),
+            Token::Str(string) if string.is_ascii() => pattern.extend(string.bytes().map(Some)),
+            _ => return None,
+        }
+    }
+
+    (!pattern.is_empty()).then_some(pattern)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(editor: &mut Editor, code: KeyCode) -> Action {
+        editor.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn press_control(editor: &mut Editor, ch: char) -> Action {
+        editor.handle_key(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::CONTROL))
+    }
+
+    fn type_text(editor: &mut Editor, text: &str) {
+        text.chars().for_each(|ch| {
+            press(editor, KeyCode::Char(ch));
+        });
+    }
+
+    fn answer(editor: &mut Editor, prompt: char, text: &str) {
+        press_control(editor, prompt);
+        type_text(editor, text);
+        press(editor, KeyCode::Enter);
+    }
+
+    #[test]
+    fn nibbles_are_typed() {
+        let mut editor = Editor::new("test", vec![0x00, 0x00, 0x00]);
+
+        type_text(&mut editor, "aB");
+        assert_eq!(editor.bytes(), &[0xAB, 0x00, 0x00]);
+        assert_eq!(editor.cursor(), 1);
+
+        type_text(&mut editor, "z1");
+        assert_eq!(editor.bytes(), &[0xAB, 0x10, 0x00]);
+        assert_eq!(editor.cursor(), 1);
+
+        press(&mut editor, KeyCode::Right);
+        type_text(&mut editor, "2");
+        assert_eq!(editor.bytes(), &[0xAB, 0x10, 0x20]);
+        assert_eq!(editor.cursor(), 2);
+
+        type_text(&mut editor, "f");
+        assert_eq!(editor.bytes(), &[0xAB, 0x10, 0x2F]);
+        assert_eq!(editor.cursor(), 2);
+        assert!(editor.is_modified());
+    }
+
+    #[test]
+    fn cursor_stays_in_empty_buffer() {
+        let mut editor = Editor::new("test", Vec::new());
+        editor.set_layout(16, 4);
+
+        for code in [
+            KeyCode::Right,
+            KeyCode::Down,
+            KeyCode::PageDown,
+            KeyCode::End,
+        ] {
+            press(&mut editor, code);
+            assert_eq!(editor.cursor(), 0);
+        }
+        type_text(&mut editor, "a1");
+
+        assert_eq!(editor.cursor(), 0);
+        assert!(editor.bytes().is_empty());
+        assert!(!editor.is_modified());
+    }
+
+    #[test]
+    fn goto_keeps_in_bounds() {
+        let mut editor = Editor::new("test", vec![0; 32]);
+
+        answer(&mut editor, 'g', "0x1F");
+        assert_eq!(editor.cursor(), 31);
+        assert_eq!(editor.message(), None);
+
+        answer(&mut editor, 'g', "32");
+        assert_eq!(editor.cursor(), 31);
+        assert_eq!(
+            editor.message(),
+            Some("The offset 32 is out of the buffer of size 32.")
+        );
+
+        answer(&mut editor, 'g', "end");
+        assert_eq!(editor.cursor(), 31);
+        assert_eq!(editor.message(), Some("'end' isn't an offset."));
+        assert_eq!(editor.prompt(), None);
+    }
+
+    #[test]
+    fn search_wraps_around() {
+        let mut editor = Editor::new("test", b"..AB....AB..".to_vec());
+
+        answer(&mut editor, 'f', "0x41 ??");
+        assert_eq!(editor.cursor(), 2);
+        assert_eq!(editor.message(), None);
+
+        press_control(&mut editor, 'n');
+        assert_eq!(editor.cursor(), 8);
+        assert_eq!(editor.message(), None);
+
+        press(&mut editor, KeyCode::F(3));
+        assert_eq!(editor.cursor(), 2);
+        assert_eq!(editor.message(), Some("The search wrapped around the end."));
+
+        answer(&mut editor, 'f', "\"BA\"");
+        assert_eq!(editor.cursor(), 2);
+        assert_eq!(editor.message(), Some("The pattern isn't found."));
+    }
+}
@@ -1,18 +1,36 @@
+mod edit;
 mod help;
 
 use crate::terminal::commander::Commander;
-use crate::terminal::output::ToOutput;
+use crate::terminal::output::{ErrorToOutput, ToOutput};
 use crate::terminal::rcommand::RunCommand;
-use crate::terminal::runner::Runner;
+use crate::terminal::runner::{ErrorClass, Runner};
+use crate::terminal::statement::StatementError;
 
-use help::help;
+use edit::edit_buffer;
+use help::{help, help_about};
 
 use std::error::Error as ErrorTrait;
 use std::fmt::{Display, Result as FmtResult};
 
 #[derive(Debug)]
 pub enum ExecuteRunCommandError<'a> {
-    CommandNotFound { command_name: &'a str },
+    CommandNotFound {
+        command_name: &'a str,
+    },
+    Include {
+        e: StatementError,
+    },
+    BufferNotFound {
+        buffer_name: &'a str,
+    },
+    /// The command works only in the interactive terminal.
+    NotInTerminal {
+        command_name: &'a str,
+    },
+    Editor {
+        message: String,
+    },
 }
 
 impl<'a> Display for ExecuteRunCommandError<'a> {
@@ -22,6 +40,22 @@ impl<'a> Display for ExecuteRunCommandError<'a> {
             Self::CommandNotFound { command_name } => {
                 write!(f, "CommandNotFound: {}", command_name)
             }
+            Self::Include { e } => {
+                write!(f, "{}", e)
+            }
+            Self::BufferNotFound { buffer_name } => {
+                write!(f, "BufferNotFound: {}", buffer_name)
+            }
+            Self::NotInTerminal { command_name } => {
+                write!(
+                    f,
+                    "NotInTerminal: {} works only in the interactive terminal",
+                    command_name
+                )
+            }
+            Self::Editor { message } => {
+                write!(f, "Editor: {}", message)
+            }
         }
     }
 }
@@ -30,7 +64,19 @@ impl<'a> ErrorTrait for ExecuteRunCommandError<'a> {}
 
 impl<'a> ToOutput for ExecuteRunCommandError<'a> {
     fn to_output(self) -> String {
-        format!("Error: {}", self)
+        format!("Error: {}\n", self)
+    }
+}
+
+impl<'a> ErrorToOutput for ExecuteRunCommandError<'a> {
+    fn class(&self) -> ErrorClass {
+        match self {
+            Self::Include { e } => e.class(),
+            Self::CommandNotFound { .. }
+            | Self::BufferNotFound { .. }
+            | Self::NotInTerminal { .. }
+            | Self::Editor { .. } => ErrorClass::RunCommand,
+        }
     }
 }
 
@@ -56,8 +102,20 @@ pub fn execute_run_command<'a, C: Commander>(
         RunCommand::Help => {
             help(runner);
         }
-        RunCommand::HelpAbout { .. } => {
-            unimplemented!();
+        RunCommand::HelpAbout { about } => {
+            if !help_about(runner, about) {
+                return Err(ExecuteRunCommandError::CommandNotFound {
+                    command_name: about,
+                });
+            }
+        }
+        RunCommand::Include { path } => {
+            runner
+                .include(path)
+                .map_err(|e| ExecuteRunCommandError::Include { e })?;
+        }
+        RunCommand::Edit { buffer_name } => {
+            edit_buffer(runner, buffer_name)?;
         }
     }
 
@@ -0,0 +1,60 @@
+use super::ExecuteRunCommandError;
+
+use crate::core::CoreCommand;
+use crate::terminal::commander::Commander;
+use crate::terminal::editor::edit;
+use crate::terminal::runner::Runner;
+
+/// The `changed_range` function returns the range of bytes from the first changed byte to the last
+/// one. If nothing is changed, the function returns `None`.
+fn changed_range(original: &[u8], edited: &[u8]) -> Option<(usize, usize)> {
+    let differs = |index: &usize| original[*index] != edited[*index];
+
+    let start = (0..original.len()).find(differs)?;
+    let end = (0..original.len()).rev().find(differs)? + 1;
+
+    Some((start, end))
+}
+
+/// The `edit_buffer` function opens the hex editor over the buffer with given name. If the editor
+/// is closed with saving, the changed bytes are written to the buffer by `buffer_write_bytes`, so
+/// the editing can be undone.
+pub fn edit_buffer<'a, C: Commander>(
+    runner: &mut Runner<C>,
+    buffer_name: &'a str,
+) -> Result<(), ExecuteRunCommandError<'a>> {
+    if !C::is_terminal() {
+        return Err(ExecuteRunCommandError::NotInTerminal {
+            command_name: "edit",
+        });
+    }
+
+    let original = runner
+        .core()
+        .buffer_bytes(buffer_name)
+        .ok_or(ExecuteRunCommandError::BufferNotFound { buffer_name })?
+        .to_vec();
+
+    let edited =
+        edit(buffer_name, original.clone()).map_err(|e| ExecuteRunCommandError::Editor {
+            message: e.to_string(),
+        })?;
+
+    let Some(edited) = edited else {
+        runner.print(format!(
+            "- Changes of buffer '{buffer_name}' are discarded.\n"
+        ));
+        return Ok(());
+    };
+
+    match changed_range(&original, &edited) {
+        Some((start, end)) => runner.execute_command(CoreCommand::BufferWriteBytes {
+            buffer_name,
+            start,
+            bytes: edited[start..end].to_vec(),
+        }),
+        None => runner.print(format!("- Buffer '{buffer_name}' isn't changed.\n")),
+    }
+
+    Ok(())
+}
@@ -1,101 +1,199 @@
+use crate::core::{find_command, ArgumentKind, CommandInfo, Parameter, Presence, COMMANDS};
 use crate::terminal::commander::Commander;
+use crate::terminal::rcommand::RUN_COMMANDS;
 use crate::terminal::runner::Runner;
 
 use colored::Colorize;
 
 const PROJECT_NAME: &str = "FModifier";
 
+/// The `BlockInfo` structure describes a block of commands. Unlike commands, blocks span multiple
+/// lines, so their templates are written by hand.
+struct BlockInfo {
+    name: &'static str,
+    template: &'static str,
+    description: &'static str,
+    examples: &'static [&'static str],
+}
+
+static BLOCKS: [BlockInfo; 3] = [
+    BlockInfo {
+        name: "for",
+        template: "for <variable> in <start>..<end> {",
+        description: "executes the commands up to the closing brace for each <variable> from <start> to <end> not inclusive.",
+        examples: &["for i in 0..4 {\n        buffer_set_byte mbr 0x1BE + i*16 0x80\n    }"],
+    },
+    BlockInfo {
+        name: "def",
+        template: "def <name>(<parameter>, ...) {",
+        description: "defines the command <name> executing the commands up to the closing brace. Its parameters are replaced by the arguments of call.",
+        examples: &["def mark_bootable(buffer) {\n        buffer_set_byte buffer 0x1BE 0x80\n    }"],
+    },
+    BlockInfo {
+        name: "if",
+        template: "if <condition> {",
+        description: "executes the commands up to the closing brace if the expression <condition> isn't 0. The block may be continued by } else { or } else if <condition> {.",
+        examples: &["if size(mbr) != 512 {\n        make_buffer mbr 512\n    }"],
+    },
+];
+
 fn stylize_command_template(command_template: &str) -> String {
     format!("{}", command_template.truecolor(170, 170, 170).italic())
 }
 
-/// The `help_mesas`
-fn help_message_stylized() -> String {
-    format!(
+/// The `template` function returns the template stylized if it's needed.
+fn template(template: &str, stylized: bool) -> String {
+    match stylized {
+        true => stylize_command_template(template),
+        false => template.to_string(),
+    }
+}
+
+/// The `help_message` function returns description of the program and list of all commands:
+/// commands of the runner, commands of the core and blocks.
+fn help_message(stylized: bool) -> String {
+    let project_name = match stylized {
+        true => PROJECT_NAME.bold().to_string(),
+        false => PROJECT_NAME.to_string(),
+    };
+
+    let mut message = format!(
         "\
 {} is a handy utility for modifying files. Its main feature is that it allows you to conveniently operate with bytes without having to think about such things as moving the file pointer.
 All commands are listed below.
-    * {help_tmp} - prints help in general or for command.
-    * {exit_tmp} - suprisingly, exits from the program.
-    * {make_buffer_tmp} - creates a buffer named <buffer_name> and sized <buffer_size>.
-    * {fill_buffer_tmp} - fills bytes from <start> inclusive to <end> not inclusive (bytes are counted from zero) of the buffer with the value value.
-    * {show_buffer_tmp} - outputs the contents of the buffer <buffer_name> to the console starting from <start> inclusive and up to <end> not inclusive (bytes are counted from zero).
-    * {buffer_info_tmp} - gives information about the buffer <buffer_name>.
-    * {buffer_set_byte_tmp} - sets the value of the index <index> of the buffer <buffer_name> to the value <value>.
-    * {create_file_tmp} - creates a file with the name <file> and the size <file_size>.
-    * {from_file_to_buffer_tmp} - copies bytes in the amount of <bytes_count> pieces from the file <file_name> starting from the <file_start>th byte (bytes count from zero) to the buffer, which is being written starting from the <buffer_start> th byte.
-    * {from_buffer_to_file_tmp} - copies bytes in the amount of <bytes_count> pieces from the buffer <buffer_name> starting from the <buffer_start>th byte (bytes are counted from zero) to the file <file_name>, which is recorded starting from <file_start>.
-    * {buffer_write_bytes_tmp} - writes a sequence of bytes <...buffer> (bytes are separated by a space) in the buffer <buffer_name> starting from the position <start> (bytes count starts from zero).
-    * {pull_out_slice_tmp} - creates a new buffer <new_buffer_name> with the exact size of <end> - <start> bytes and writes there the values from the buffer <buffer_name> starting with <start> and ending with <end>.
-    * {merge_buffers_tmp} - creates a new buffer <new_buffer_name>, in which the first part of the bytes is copied from <left_buffer_name>, and the second part is copied from the buffer <right_buffer_name>. That is, the size of the new buffer is equal to the sum of the other two.
-    * {open_file_tmp} - opens a file named <file_name> in the current directory strictly for reading.
-    * {show_file_tmp} - reads a file named <file_name> starting with <start> and ending with <end>.
 ",
-        PROJECT_NAME.bold(),
-        help_tmp = stylize_command_template("help [<command>]"),
-        exit_tmp = stylize_command_template("exit"),
-        make_buffer_tmp = stylize_command_template("make_buffer <buffer_name> <buffer_size>"),
-        fill_buffer_tmp = stylize_command_template("fill_buffer <buffer_name> <value> <start> <end>"),
-        show_buffer_tmp = stylize_command_template("show_buffer <buffer_name> <start> <end>"),
-        buffer_info_tmp = stylize_command_template("buffer_info <buffer_name>"),
-        buffer_set_byte_tmp = stylize_command_template("buffer_set_byte <buffer_name> <index> <value>"),
-        create_file_tmp = stylize_command_template("create_file <file_name> <file_size>"),
-        from_file_to_buffer_tmp = stylize_command_template("from_file_to_buffer <file_name> <buffer_name> <bytes_count> <file_start> <buffer_start>"),
-        from_buffer_to_file_tmp = stylize_command_template("from_buffer_to_file <buffer_name> <file_name> <bytes_count> <buffer_start> <file_start>"),
-        buffer_write_bytes_tmp = stylize_command_template("buffer_write_bytes <buffer_name> <start> <...bytes>"),
-        pull_out_slice_tmp = stylize_command_template("pull_out_slice <buffer_name> <new_buffer_name> <start> <end>"),
-        merge_buffers_tmp = stylize_command_template("merge_buffers <left_buffer_name> <right_buffer_name> <new_buffer_name>"),
-        open_file_tmp = stylize_command_template("open_file <file_name>"),
-        show_file_tmp = stylize_command_template("show_file <file_name> <start> <end>"),
-    )
+        project_name
+    );
+
+    for info in RUN_COMMANDS.iter().chain(COMMANDS.iter()) {
+        message.push_str(&format!(
+            "    * {} - {}\n",
+            template(&info.template(), stylized),
+            info.description
+        ));
+    }
+    for block in &BLOCKS {
+        message.push_str(&format!(
+            "    * {} - {}\n",
+            template(block.template, stylized),
+            block.description
+        ));
+    }
+
+    message
+}
+
+/// The `parameter_message` function returns line describing the parameter in help for command.
+fn parameter_message(parameter: &Parameter) -> String {
+    let description = match (parameter.kind, parameter.presence) {
+        (ArgumentKind::Flag(_), _) => "the word itself, may be omitted".to_string(),
+        (kind, Presence::Optional) => format!("{kind}, may be omitted"),
+        (kind, Presence::Variadic) => format!("{kind}, any count of them"),
+        (kind, Presence::Required) => kind.to_string(),
+    };
+
+    format!("    {} - {}.\n", parameter.template(), description)
 }
 
-fn help_message_regular() -> String {
+/// The `examples_message` function returns list of examples in help for command.
+fn examples_message(examples: &[&str]) -> String {
+    let mut message = "Examples:\n".to_string();
+    for example in examples {
+        message.push_str(&format!("    {example}\n"));
+    }
+
+    message
+}
+
+/// The `command_message` function returns help for the built-in command: its template,
+/// description, parameters and examples.
+fn command_message(info: &CommandInfo, stylized: bool) -> String {
+    let mut message = format!(
+        "{} - {}\n",
+        template(&info.template(), stylized),
+        info.description
+    );
+
+    // Punctuation like `=` of `let` is a part of template, not an argument.
+    let parameters: Vec<&Parameter> = info
+        .parameters
+        .iter()
+        .filter(|parameter| !matches!(parameter.kind, ArgumentKind::Punct(_)))
+        .collect();
+    if !parameters.is_empty() {
+        message.push_str("Arguments:\n");
+        for parameter in parameters {
+            message.push_str(&parameter_message(parameter));
+        }
+    }
+
+    message.push_str(&examples_message(info.examples));
+    message
+}
+
+/// The `block_message` function returns help for the block.
+fn block_message(block: &BlockInfo, stylized: bool) -> String {
     format!(
-        "\
-{} is a handy utility for modifying files. Its main feature is that it allows you to conveniently operate with bytes without having to think about such things as moving the file pointer.
-All commands are listed below.
-    * {help_tmp} - prints help in general or for command.
-    * {exit_tmp} - suprisingly, exits from the program.
-    * {make_buffer_tmp} - creates a buffer named <buffer_name> and sized <buffer_size>.
-    * {fill_buffer_tmp} - fills bytes from <start> inclusive to <end> not inclusive (bytes are counted from zero) of the buffer with the value value.
-    * {show_buffer_tmp} - outputs the contents of the buffer <buffer_name> to the console starting from <start> inclusive and up to <end> not inclusive (bytes are counted from zero).
-    * {buffer_info_tmp} - gives information about the buffer <buffer_name>.
-    * {buffer_set_byte_tmp} - sets the value of the index <index> of the buffer <buffer_name> to the value <value>.
-    * {create_file_tmp} - creates a file with the name <file> and the size <file_size>.
-    * {from_file_to_buffer_tmp} - copies bytes in the amount of <bytes_count> pieces from the file <file_name> starting from the <file_start>th byte (bytes count from zero) to the buffer, which is being written starting from the <buffer_start> th byte.
-    * {from_buffer_to_file_tmp} - copies bytes in the amount of <bytes_count> pieces from the buffer <buffer_name> starting from the <buffer_start>th byte (bytes are counted from zero) to the file <file_name>, which is recorded starting from <file_start>.
-    * {buffer_write_bytes_tmp} - writes a sequence of bytes <...buffer> (bytes are separated by a space) in the buffer <buffer_name> starting from the position <start> (bytes count starts from zero).
-    * {pull_out_slice_tmp} - creates a new buffer <new_buffer_name> with the exact size of <end> - <start> bytes and writes there the values from the buffer <buffer_name> starting with <start> and ending with <end>.
-    * {merge_buffers_tmp} - creates a new buffer <new_buffer_name>, in which the first part of the bytes is copied from <left_buffer_name>, and the second part is copied from the buffer <right_buffer_name>. That is, the size of the new buffer is equal to the sum of the other two.
-    * {open_file_tmp} - opens a file named <file_name> in the current directory strictly for reading.
-    * {show_file_tmp} - reads a file named <file_name> starting with <start> and ending with <end>.
-",
-        PROJECT_NAME,
-        help_tmp = "help [<command>]",
-        exit_tmp = "exit",
-        make_buffer_tmp = "make_buffer <buffer_name> <buffer_size>",
-        fill_buffer_tmp = "fill_buffer <buffer_name> <value> <start> <end>",
-        show_buffer_tmp = "show_buffer <buffer_name> <start> <end>",
-        buffer_info_tmp = "buffer_info <buffer_name>",
-        buffer_set_byte_tmp = "buffer_set_byte <buffer_name> <index> <value>",
-        create_file_tmp = "create_file <file_name> <file_size>",
-        from_file_to_buffer_tmp = "from_file_to_buffer <file_name> <buffer_name> <bytes_count> <file_start> <buffer_start>",
-        from_buffer_to_file_tmp = "from_buffer_to_file <buffer_name> <file_name> <bytes_count> <buffer_start> <file_start>",
-        buffer_write_bytes_tmp = "buffer_write_bytes <buffer_name> <start> <...bytes>",
-        pull_out_slice_tmp = "pull_out_slice <buffer_name> <new_buffer_name> <start> <end>",
-        merge_buffers_tmp = "merge_buffers <left_buffer_name> <right_buffer_name> <new_buffer_name>",
-        open_file_tmp = "open_file <file_name>",
-        show_file_tmp = "show_file <file_name> <start> <end>",
+        "{} - {}\n{}",
+        template(block.template, stylized),
+        block.description,
+        examples_message(block.examples)
     )
 }
 
+/// The `user_commands_message` function returns list of commands defined by `def`. If there're no
+/// such commands, the function returns empty string.
+fn user_commands_message(templates: Vec<String>, stylized: bool) -> String {
+    if templates.is_empty() {
+        return String::new();
+    }
+
+    let mut message = "User-defined commands are listed below.\n".to_string();
+    for user_template in templates {
+        message.push_str(&format!(
+            "    * {} - user-defined command.\n",
+            template(&user_template, stylized)
+        ));
+    }
+
+    message
+}
+
 /// The `help` function prints the help message. The function takes into account the fact that
 /// runner can be opened either in terminal mode or in regular mode.
+///
+/// Commands defined by `def` are listed after the built-in ones.
 pub fn help<C: Commander>(runner: &mut Runner<C>) {
-    if C::is_terminal() {
-        runner.print(help_message_stylized());
+    let templates = runner.procedure_templates();
+
+    runner.print(help_message(C::is_terminal()));
+    runner.print(user_commands_message(templates, C::is_terminal()));
+}
+
+/// The `help_about` function prints help for the command or the block with given name. If there's
+/// no such one, the function returns `false`.
+///
+/// A user-defined command is looked for first, since it's called instead of built-in one.
+pub fn help_about<C: Commander>(runner: &mut Runner<C>, about: &str) -> bool {
+    let stylized = C::is_terminal();
+
+    let message = if let Some(user_template) = runner.procedure_template(about) {
+        format!(
+            "{} - user-defined command.\n",
+            template(&user_template, stylized)
+        )
+    } else if let Some(info) = RUN_COMMANDS
+        .iter()
+        .find(|info| info.name == about)
+        .or_else(|| find_command(about))
+    {
+        command_message(info, stylized)
+    } else if let Some(block) = BLOCKS.iter().find(|block| block.name == about) {
+        block_message(block, stylized)
     } else {
-        runner.print(help_message_regular());
-    }
+        return false;
+    };
+
+    runner.print(message);
+    true
 }
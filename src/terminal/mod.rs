@@ -3,8 +3,12 @@
 mod exec_rcommand;
 mod output;
 mod rcommand;
+mod statement;
+#[allow(clippy::module_inception)]
 mod terminal;
 mod tokenizer;
 
+pub mod arguments;
 pub mod commander;
+pub mod editor;
 pub mod runner;
@@ -1,10 +1,52 @@
 mod core_error_impl;
 mod core_output_impl;
+mod core_output_json;
 mod parse_error_impl;
+mod statement_error_impl;
 mod tokenize_error_impl;
 
+use super::runner::ErrorClass;
+
+use crate::core::ColorMode;
+
+use serde_json::Value;
+
+use std::env;
+use std::fmt::Display;
+use std::io::{stdout, IsTerminal};
+
+/// The environment variable turning colors off if it isn't empty.
+const NO_COLOR_VARIABLE: &str = "NO_COLOR";
+
+/// The `is_color_enabled` function tells whether output is colored in given mode. In the `auto`
+/// mode, it's colored if the standard output is a terminal and `NO_COLOR` isn't set.
+pub fn is_color_enabled(mode: ColorMode) -> bool {
+    match mode {
+        ColorMode::On => true,
+        ColorMode::Off => false,
+        ColorMode::Auto => {
+            stdout().is_terminal()
+                && env::var_os(NO_COLOR_VARIABLE).is_none_or(|value| value.is_empty())
+        }
+    }
+}
+
 pub trait ToOutput {
     /// The `output` function turns the self into a string. In the end of returning string there
     /// must be the new-line symbol.
     fn to_output(self) -> String;
 }
+
+/// The `ToJson` trait turns results of commands into JSON values. It's used instead of `ToOutput`
+/// if the runner writes its output as JSON.
+pub trait ToJson {
+    fn to_json(&self) -> Value;
+}
+
+/// The `ErrorToOutput` trait is implemented by errors the runner outputs. In JSON, an error is
+/// written by its message.
+pub trait ErrorToOutput: ToOutput + Display {
+    /// The `class` function returns class of the error. In strict mode, it determines the exit
+    /// code.
+    fn class(&self) -> ErrorClass;
+}
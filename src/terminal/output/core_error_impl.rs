@@ -1,9 +1,16 @@
-use super::ToOutput;
+use super::{ErrorToOutput, ToOutput};
 
 use crate::core::core_e::CoreError;
+use crate::terminal::runner::ErrorClass;
 
 impl ToOutput for CoreError {
     fn to_output(self) -> String {
         format!("Error: {}\n", self)
     }
 }
+
+impl ErrorToOutput for CoreError {
+    fn class(&self) -> ErrorClass {
+        ErrorClass::Core
+    }
+}
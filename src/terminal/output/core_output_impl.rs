@@ -3,14 +3,17 @@ use super::ToOutput;
 use crate::core::output::*;
 
 mod string_rectangle;
+mod stringify_diff;
 mod stringify_table;
 mod stringify_table_2_col;
 
+use stringify_diff::stringify_diff;
 use stringify_table::stringify_table;
 use stringify_table_2_col::stringify_table_2_col;
 
 const TABLE_CAPTION: &str = "Table:";
 const TABLE_2COL_CAPTION: &str = "Table:";
+const DIFF_CAPTION: &str = "Diff:";
 
 fn stringify_info_line(info_line: &InfoLine, write_to: &mut String) {
     write_to.push_str("- ");
@@ -27,7 +30,7 @@ fn stringify_infos(infos: &[InfoLine], write_to: &mut String) {
         return;
     }
 
-    infos[..infos.len() - 1].into_iter().for_each(|info_line| {
+    infos[..infos.len() - 1].iter().for_each(|info_line| {
         stringify_info_line(info_line, write_to);
         write_to.push('\n');
     });
@@ -43,11 +46,25 @@ fn stringify_other_info(other_info: &OtherInfo, write_to: &mut String) {
 
             stringify_table_2_col(data, write_to);
         }
-        OtherInfo::BigTable { table } => {
+        OtherInfo::BigTable {
+            table,
+            side_panel,
+            colors,
+        } => {
             write_to.push_str(TABLE_2COL_CAPTION);
             write_to.push('\n');
 
-            stringify_table(table, write_to);
+            stringify_table(table, side_panel.as_deref(), colors.as_ref(), write_to);
+        }
+        OtherInfo::Diff {
+            left_name,
+            right_name,
+            ranges,
+        } => {
+            write_to.push_str(DIFF_CAPTION);
+            write_to.push('\n');
+
+            stringify_diff(left_name, right_name, ranges, write_to);
         }
     }
 }
@@ -60,7 +77,7 @@ fn stringify_other_infos(other_infos: &[OtherInfo], write_to: &mut String) {
     }
 
     other_infos[..other_infos.len() - 1]
-        .into_iter()
+        .iter()
         .for_each(|other_info| {
             stringify_other_info(other_info, write_to);
             write_to.push('\n');
@@ -84,7 +101,7 @@ fn stringify_warnings(warnings: &[Warning], write_to: &mut String) {
     }
 
     warnings[..warnings.len() - 1]
-        .into_iter()
+        .iter()
         .for_each(|other_info| {
             stringify_warning(other_info, write_to);
             write_to.push('\n');
@@ -100,19 +117,19 @@ impl ToOutput for CoreOutput {
         let infos = self.info();
         if !infos.is_empty() {
             stringify_infos(self.info(), &mut output);
-            output.push_str("\n");
+            output.push('\n');
         }
 
         let other_infos = self.other_info();
         if !other_infos.is_empty() {
             stringify_other_infos(other_infos, &mut output);
-            output.push_str("\n");
+            output.push('\n');
         }
 
         let warnings = self.warnings();
         if !warnings.is_empty() {
             stringify_warnings(self.warnings(), &mut output);
-            output.push_str("\n");
+            output.push('\n');
         }
 
         output
@@ -1,3 +1,5 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
 const ANY_INDEX: usize = 0;
 const ANY_USIZE_VALUE: usize = 0;
 
@@ -482,7 +484,7 @@ impl StringRectangle {
             lines: self
                 .lines
                 .into_iter()
-                .zip(other.lines.into_iter())
+                .zip(other.lines)
                 .map(|(mut self_line, other_line)| {
                     self_line.extend(other_line);
                     self_line
@@ -499,7 +501,7 @@ impl StringRectangle {
             lines: self
                 .lines
                 .into_iter()
-                .zip(other.lines.into_iter())
+                .zip(other.lines)
                 .map(|(self_line, mut other_line)| {
                     other_line.extend(self_line);
                     other_line
@@ -552,26 +554,24 @@ impl From<&str> for StringRectangle {
     }
 }
 
-impl ToString for StringRectangle {
-    fn to_string(&self) -> String {
+impl Display for StringRectangle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         if self.lines.is_empty() {
-            return "".to_string();
+            return Ok(());
         }
 
-        let mut string = String::new();
-
         for line in &self.lines[..self.lines.len() - 1] {
             for &ch in line {
-                string.push(ch);
+                write!(f, "{}", ch)?;
             }
-            string.push('\n');
+            writeln!(f)?;
         }
 
         for &ch in &self.lines[self.lines.len() - 1] {
-            string.push(ch);
+            write!(f, "{}", ch)?;
         }
 
-        string
+        Ok(())
     }
 }
 
@@ -0,0 +1,111 @@
+use crate::core::output::DiffRange;
+
+use colored::Colorize;
+
+const BYTES_IN_ROW: usize = 16;
+
+const SEPARATOR: &str = " ┃ ";
+const MISSING_BYTE: &str = "--";
+
+/// The `CELL_WIDTH` is width of one byte with the space after it.
+const CELL_WIDTH: usize = 3;
+const SIDE_WIDTH: usize = BYTES_IN_ROW * CELL_WIDTH - 1;
+
+fn stringify_byte(byte: Option<u8>) -> String {
+    match byte {
+        Some(byte) => format!("{:02x}", byte),
+        None => MISSING_BYTE.to_string(),
+    }
+}
+
+/// The `stringify_side` function writes bytes of one side of the row. Bytes that differ from bytes
+/// of another side are highlighted by given function. The side is padded to `SIDE_WIDTH` in the
+/// end if `pad` is set.
+fn stringify_side(
+    bytes: &[Option<u8>],
+    other: &[Option<u8>],
+    highlight: fn(String) -> String,
+    pad: bool,
+) -> String {
+    let cells: Vec<String> = bytes
+        .iter()
+        .zip(other)
+        .map(|(&byte, &other_byte)| {
+            let cell = stringify_byte(byte);
+
+            if byte != other_byte {
+                highlight(cell)
+            } else {
+                cell
+            }
+        })
+        .collect();
+
+    let mut side = cells.join(" ");
+
+    if pad {
+        let visible_width = (bytes.len() * CELL_WIDTH).saturating_sub(1);
+        side.push_str(&" ".repeat(SIDE_WIDTH - visible_width));
+    }
+
+    side
+}
+
+fn highlight_left(cell: String) -> String {
+    cell.red().bold().to_string()
+}
+
+fn highlight_right(cell: String) -> String {
+    cell.green().bold().to_string()
+}
+
+/// The `stringify_range` function writes rows of one range. Each row starts with the index of its
+/// first byte.
+fn stringify_range(range: &DiffRange, offset_width: usize, rows: &mut Vec<String>) {
+    let lefts = range.left().chunks(BYTES_IN_ROW);
+    let rights = range.right().chunks(BYTES_IN_ROW);
+
+    for (row_index, (left, right)) in lefts.zip(rights).enumerate() {
+        let offset = range.start() + row_index * BYTES_IN_ROW;
+
+        rows.push(format!(
+            "{offset:<offset_width$}{SEPARATOR}{}{SEPARATOR}{}",
+            stringify_side(left, right, highlight_left, true),
+            stringify_side(right, left, highlight_right, false),
+        ));
+    }
+}
+
+/// The `stringify_diff` function writes to given string ranges where two buffers or files differ.
+/// The ranges are shown as side-by-side rows of bytes; differing bytes are highlighted if colors
+/// are on. There's no
+/// extra characters in the end and in the start.
+///
+/// #### Example
+/// ```text
+///     ┃ left                                            ┃ right
+/// 510 ┃ 55 aa                                           ┃ 00 00
+/// ```
+pub fn stringify_diff(
+    left_name: &str,
+    right_name: &str,
+    ranges: &[DiffRange],
+    write_to: &mut String,
+) {
+    let offset_width = ranges
+        .iter()
+        .map(|range| (range.start() + range.len()).to_string().len())
+        .max()
+        .unwrap_or(0);
+
+    let mut rows = vec![format!(
+        "{:offset_width$}{SEPARATOR}{left_name:<SIDE_WIDTH$}{SEPARATOR}{right_name}",
+        ""
+    )];
+
+    for range in ranges {
+        stringify_range(range, offset_width, &mut rows);
+    }
+
+    write_to.push_str(&rows.join("\n"));
+}
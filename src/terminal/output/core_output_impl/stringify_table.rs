@@ -2,6 +2,8 @@ use super::string_rectangle::StringRectangle;
 
 use crate::core::output::*;
 
+use colored::Colorize;
+
 const NEW_LINE: char = '\n';
 
 fn make_column(lines: &[String]) -> StringRectangle {
@@ -10,7 +12,7 @@ fn make_column(lines: &[String]) -> StringRectangle {
     }
 
     let content = lines[1..]
-        .into_iter()
+        .iter()
         .fold(lines[0].clone(), |acc, string| {
             format!("{acc}{NEW_LINE}{string}")
         });
@@ -50,15 +52,117 @@ fn table_data_to_rectangle(table: &Table) -> StringRectangle {
     matrix_rectangle
 }
 
-/// The `stringify_table` function writes to given string stringified given table. There's no extra
-/// characters in the end and in the start.
-pub fn stringify_table(table: &Table, write_to: &mut String) {
+/// The `rectangle_side_panel_separator` is string rectangle that need to be inserted between the
+/// last column of elements of table and the side panel.
+fn rectangle_side_panel_separator(line_count: usize) -> StringRectangle {
+    make_column(&vec!["┃ ".to_string(); line_count])
+}
+
+/// The `paint` function colors given text according to the class of its bytes: zero bytes are dim,
+/// printable characters are green, control characters are yellow and `0xFF` bytes are red.
+fn paint(text: &str, class: ByteClass) -> String {
+    match class {
+        ByteClass::Zero => text.dimmed().to_string(),
+        ByteClass::Printable => text.green().to_string(),
+        ByteClass::Control => text.yellow().to_string(),
+        ByteClass::Full => text.red().to_string(),
+        ByteClass::Other => text.to_string(),
+    }
+}
+
+/// The `paint_line` function colors spans of the line. Spans are given by ranges of characters in
+/// ascending order.
+fn paint_line(line: &str, spans: &[(usize, usize, ByteClass)]) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let mut painted = String::new();
+    let mut position = 0;
+
+    for &(start, end, class) in spans {
+        painted.extend(&chars[position..start]);
+        painted.push_str(&paint(&chars[start..end].iter().collect::<String>(), class));
+        position = end;
+    }
+    painted.extend(&chars[position..]);
+
+    painted
+}
+
+/// The `paint_table` function colors lines of the stringified table. Positions of cells are found
+/// the same way the table is built: each column is as wide as its widest cell. Spaces aligning
+/// cells aren't colored.
+fn paint_table(table: &Table, colors: &TableColors, content: &str) -> String {
+    let width = |string: &String| string.chars().count();
+    let names_width = table.row_names().iter().map(width).max().unwrap_or(0);
+
+    let mut column_starts = Vec::new();
+    let mut position = names_width + " ┃ ".chars().count();
+    for column_index in 0..table.column_count() {
+        column_starts.push(position);
+        position += (0..table.row_count())
+            .map(|row_index| width(table.get(row_index, column_index).unwrap()))
+            .max()
+            .unwrap_or(0)
+            + 1;
+    }
+    let side_panel_start = position + "┃ ".chars().count();
+
+    content
+        .split(NEW_LINE)
+        .enumerate()
+        .map(|(row_index, line)| {
+            let mut spans = Vec::new();
+
+            for (column_index, class) in colors.cells[row_index].iter().enumerate() {
+                let cell = table.get(row_index, column_index).unwrap();
+                let start =
+                    column_starts[column_index] + width(cell) - cell.trim_start().chars().count();
+                if let Some(class) = class {
+                    spans.push((start, column_starts[column_index] + width(cell), *class));
+                }
+            }
+            if line.chars().count() > side_panel_start {
+                for (index, class) in colors.side_panel[row_index].iter().enumerate() {
+                    if let Some(class) = class {
+                        spans.push((
+                            side_panel_start + index,
+                            side_panel_start + index + 1,
+                            *class,
+                        ));
+                    }
+                }
+            }
+
+            paint_line(line, &spans)
+        })
+        .collect::<Vec<_>>()
+        .join(&NEW_LINE.to_string())
+}
+
+/// The `stringify_table` function writes to given string stringified given table. If there's the
+/// side panel, its lines are written to the right of rows. If there're colors, bytes are colored
+/// by their classes. There's no extra characters in the end and in the start.
+pub fn stringify_table(
+    table: &Table,
+    side_panel: Option<&[String]>,
+    colors: Option<&TableColors>,
+    write_to: &mut String,
+) {
     let rows_rectangle = make_column(table.row_names());
     let data_matrix = table_data_to_rectangle(table);
 
-    let full_table_rectangle = rows_rectangle
+    let mut full_table_rectangle = rows_rectangle
         .place_right_top(rectangle_row_element_separator(table.row_count()))
         .place_right_top(data_matrix);
 
-    write_to.push_str(full_table_rectangle.to_string().as_str());
+    if let Some(lines) = side_panel {
+        full_table_rectangle = full_table_rectangle
+            .place_right_top(rectangle_side_panel_separator(table.row_count()))
+            .place_right_top(make_column(lines));
+    }
+
+    let content = full_table_rectangle.to_string();
+    match colors {
+        Some(colors) => write_to.push_str(&paint_table(table, colors, &content)),
+        None => write_to.push_str(&content),
+    }
 }
@@ -10,7 +10,7 @@ fn stringify_couples(couples: &[(String, String)], write_to: &mut String) {
     }
 
     couples[0..couples.len() - 1]
-        .into_iter()
+        .iter()
         .for_each(|couple| {
             stringify_couple(couple, write_to);
             write_to.push('\n');
@@ -23,7 +23,7 @@ fn stringify_couples(couples: &[(String, String)], write_to: &mut String) {
 /// extra chars in the end and in the start.
 pub fn stringify_table_2_col(table: &[(String, String)], write_to: &mut String) {
     debug_assert!(table
-        .into_iter()
+        .iter()
         .all(|(k, v)| !k.contains('\n') && !v.contains('\n')));
 
     stringify_couples(table, write_to);
@@ -0,0 +1,78 @@
+use super::ToJson;
+
+use crate::core::output::*;
+
+use serde_json::{json, Value};
+
+/// The `table_json` function returns the table of bytes as names of rows and columns and cells by
+/// rows. Spaces aligning cells are trimmed.
+fn table_json(table: &Table, side_panel: Option<&[String]>) -> Value {
+    let rows: Vec<Vec<&str>> = (0..table.row_count())
+        .map(|row| {
+            (0..table.column_count())
+                .map(|column| table.get(row, column).unwrap().trim())
+                .collect()
+        })
+        .collect();
+
+    json!({
+        "type": "table",
+        "row_names": table.row_names(),
+        "column_names": table.column_names(),
+        "rows": rows,
+        "side_panel": side_panel,
+    })
+}
+
+/// The `diff_json` function returns ranges of the diff. A byte is `null` if the sequence has
+/// ended before it.
+fn diff_json(left_name: &str, right_name: &str, ranges: &[DiffRange]) -> Value {
+    let ranges: Vec<Value> = ranges
+        .iter()
+        .map(|range| {
+            json!({
+                "start": range.start(),
+                "left": range.left(),
+                "right": range.right(),
+            })
+        })
+        .collect();
+
+    json!({
+        "type": "diff",
+        "left_name": left_name,
+        "right_name": right_name,
+        "ranges": ranges,
+    })
+}
+
+impl ToJson for OtherInfo {
+    fn to_json(&self) -> Value {
+        match self {
+            OtherInfo::Table2Column { data } => json!({
+                "type": "table_2_column",
+                "rows": data,
+            }),
+            OtherInfo::BigTable {
+                table, side_panel, ..
+            } => table_json(table, side_panel.as_deref()),
+            OtherInfo::Diff {
+                left_name,
+                right_name,
+                ranges,
+            } => diff_json(left_name, right_name, ranges),
+        }
+    }
+}
+
+impl ToJson for CoreOutput {
+    fn to_json(&self) -> Value {
+        let other_info: Vec<Value> = self.other_info().iter().map(ToJson::to_json).collect();
+
+        json!({
+            "info": self.info(),
+            "warnings": self.warnings(),
+            "other_info": other_info,
+        })
+    }
+}
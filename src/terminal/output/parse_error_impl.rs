@@ -1,9 +1,16 @@
-use super::ToOutput;
+use super::{ErrorToOutput, ToOutput};
 
 use crate::core::token_parser::ParseError;
+use crate::terminal::runner::ErrorClass;
 
 impl ToOutput for ParseError {
     fn to_output(self) -> String {
         format!("Error: {}\n", self)
     }
 }
+
+impl ErrorToOutput for ParseError {
+    fn class(&self) -> ErrorClass {
+        ErrorClass::Parse
+    }
+}
@@ -0,0 +1,16 @@
+use crate::terminal::runner::ErrorClass;
+use crate::terminal::statement::StatementError;
+
+use super::{ErrorToOutput, ToOutput};
+
+impl ToOutput for StatementError {
+    fn to_output(self) -> String {
+        format!("Error: {}\n", self)
+    }
+}
+
+impl ErrorToOutput for StatementError {
+    fn class(&self) -> ErrorClass {
+        ErrorClass::Statement
+    }
+}
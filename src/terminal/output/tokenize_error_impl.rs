@@ -1,9 +1,16 @@
+use crate::terminal::runner::ErrorClass;
 use crate::terminal::tokenizer::TokenizeError;
 
-use super::ToOutput;
+use super::{ErrorToOutput, ToOutput};
 
-impl<'a> ToOutput for TokenizeError<'a> {
+impl ToOutput for TokenizeError {
     fn to_output(self) -> String {
         format!("Error: {}\n", self)
     }
 }
+
+impl ErrorToOutput for TokenizeError {
+    fn class(&self) -> ErrorClass {
+        ErrorClass::Tokenize
+    }
+}
@@ -1,27 +1,74 @@
-use crate::core::Token;
+use crate::core::{validate_arguments, ArgumentKind, CommandInfo, Parameter, ParseResult, Token};
 
+const EDIT_WORD: &str = "edit";
 const EXIT_WORD: &str = "exit";
 const HELP_WORD: &str = "help";
+const INCLUDE_WORD: &str = "include";
+
+/// Commands executed by the runner itself in the order they're listed by `help`.
+pub static RUN_COMMANDS: [CommandInfo; 4] = [
+    CommandInfo {
+        name: HELP_WORD,
+        parameters: &[Parameter::optional("command", ArgumentKind::Name)],
+        description: "prints help in general or for command.",
+        examples: &["help", "help fill_buffer"],
+    },
+    CommandInfo {
+        name: EXIT_WORD,
+        parameters: &[],
+        description: "suprisingly, exits from the program.",
+        examples: &["exit"],
+    },
+    CommandInfo {
+        name: INCLUDE_WORD,
+        parameters: &[Parameter::required("path", ArgumentKind::Path)],
+        description: "executes commands of the script <path> sharing buffers, files and variables. A relative path is taken relative to the directory of the including script.",
+        examples: &["include common.fmod", "include \"lib/partitions.fmod\""],
+    },
+    CommandInfo {
+        name: EDIT_WORD,
+        parameters: &[Parameter::required("buffer_name", ArgumentKind::Name)],
+        description: "opens the full-screen hex editor over the buffer <buffer_name>. Arrows, Page Up/Down, Home/End move the cursor, Tab switches between the hex and ASCII panes, typed hex digits or characters overwrite bytes, Ctrl-G goes to an offset, Ctrl-F searches bytes like find_bytes and Ctrl-N finds the next match. Ctrl-S writes the changes to the buffer and closes the editor; Ctrl-Q and Esc close it without them. Only in the interactive terminal.",
+        examples: &["edit mbr"],
+    },
+];
 
 pub enum RunCommand {
     Exit,
     Help,
     HelpAbout { about: String },
+    Include { path: String },
+    Edit { buffer_name: String },
 }
 
 /// The `parse_run_command` function takes a sequence of tokens and tries parse them into
-/// `RunCommand`. If there's no way to make given tokens into `RunCommand`, the function is gonna
-/// return `None`.
-pub fn parse_run_command(tokens: &[Token]) -> Option<RunCommand> {
-    match tokens.get(0)? {
-        Token::Word(exit) if exit == EXIT_WORD => Some(RunCommand::Exit),
-        Token::Word(help) if help == HELP_WORD => match tokens.get(1) {
-            Some(Token::Word(about)) => Some(RunCommand::HelpAbout {
-                about: about.clone(),
-            }),
-            None => Some(RunCommand::Help),
-            _ => None,
-        },
-        _ => None,
+/// `RunCommand`. If the tokens aren't a runner's command, the function is gonna return `None`. If
+/// they are, but arguments don't fit the command, the function returns the error of parsing.
+pub fn parse_run_command(tokens: &[Token]) -> Option<ParseResult<RunCommand>> {
+    let Some(Token::Word(word)) = tokens.first() else {
+        return None;
+    };
+    let info = RUN_COMMANDS.iter().find(|info| info.name == word)?;
+
+    if let Err(e) = validate_arguments(info, tokens) {
+        return Some(Err(e));
     }
+
+    // The arguments are validated, so they're of the expected kinds.
+    let command = match (info.name, tokens.get(1)) {
+        (EXIT_WORD, _) => RunCommand::Exit,
+        (HELP_WORD, Some(Token::Word(about))) => RunCommand::HelpAbout {
+            about: about.clone(),
+        },
+        (HELP_WORD, _) => RunCommand::Help,
+        (INCLUDE_WORD, Some(Token::Word(path) | Token::Str(path))) => {
+            RunCommand::Include { path: path.clone() }
+        }
+        (EDIT_WORD, Some(Token::Word(buffer_name))) => RunCommand::Edit {
+            buffer_name: buffer_name.clone(),
+        },
+        _ => unreachable!(),
+    };
+
+    Some(Ok(command))
 }
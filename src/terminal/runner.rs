@@ -1,16 +1,106 @@
+use super::arguments::Arguments;
 use super::commander::Commander;
 use super::exec_rcommand::execute_run_command;
-use super::output::ToOutput;
+use super::output::{is_color_enabled, ErrorToOutput, ToJson, ToOutput};
 use super::rcommand::parse_run_command;
-use super::tokenizer::tokenize;
+use super::statement::{read_statement, NumberedLine, Procedure, Statement, StatementError};
+use super::tokenizer::{tokenize, TokenizeError};
 
 use crate::core::parse_tokens;
 use crate::core::Core;
+use crate::core::CoreCommand;
+use crate::core::Token;
+
+use colored::control;
+use serde_json::{json, Value};
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The `MAX_CALL_DEPTH` is how deep user-defined commands can call each other.
+const MAX_CALL_DEPTH: usize = 64;
+
+/// The `ErrorClass` enumeration is a kind of error the runner outputs. In strict mode, the class
+/// of error that stopped the runner determines the exit code of the program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorClass {
+    Tokenize,
+    Parse,
+    Core,
+    /// Errors of blocks, user-defined commands and `include`.
+    Statement,
+    /// Errors of commands executed by the runner itself like `help`.
+    RunCommand,
+}
+
+impl ErrorClass {
+    /// The `exit_code` function returns exit code of the program stopped by the error. Code 1 is
+    /// left for other failures and code 2 for invalid command line arguments.
+    pub fn exit_code(self) -> i32 {
+        match self {
+            ErrorClass::Tokenize => 3,
+            ErrorClass::Parse => 4,
+            ErrorClass::Core => 5,
+            ErrorClass::Statement => 6,
+            ErrorClass::RunCommand => 7,
+        }
+    }
+
+    /// The `name` function returns name of the class used in JSON output.
+    pub fn name(self) -> &'static str {
+        match self {
+            ErrorClass::Tokenize => "tokenize",
+            ErrorClass::Parse => "parse",
+            ErrorClass::Core => "core",
+            ErrorClass::Statement => "statement",
+            ErrorClass::RunCommand => "run_command",
+        }
+    }
+}
+
+/// The `OutputFormat` enumeration is how the runner writes results of commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    /// One JSON object per command on a line. Objects tell the status of command, the source and
+    /// the line it's written at and its output or error.
+    Json,
+}
+
+impl OutputFormat {
+    pub fn from_name(name: &str) -> Option<OutputFormat> {
+        match name {
+            "text" => Some(OutputFormat::Text),
+            "json" => Some(OutputFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+/// The `Location` structure is a place in a source of commands: the commander or an included file.
+struct Location {
+    source: String,
+    /// The canonical path of the source if it's a file. It's used to detect include cycles.
+    path: Option<PathBuf>,
+    /// The line of the command being executed.
+    line: usize,
+}
+
+impl Location {
+    fn new(source: String) -> Self {
+        Location {
+            path: fs::canonicalize(&source).ok(),
+            source,
+            line: 0,
+        }
+    }
+}
 
 /// The `Runner` structure is iterator in which each iteration means following actions:
-/// 1. Take commander's command.
-/// 2. Parse the command.
-/// 3. Execute the command.
+/// 1. Take commander's statement: a command or a block of commands spanning multiple lines.
+/// 2. Evaluate expressions and parse each command.
+/// 3. Execute each command.
 /// 4. Output result.
 ///
 /// Iterations ends when ends commands.
@@ -18,14 +108,41 @@ pub struct Runner<C: Commander> {
     core: Core,
     commander: C,
     completed: bool,
+    /// User-defined commands.
+    procedures: HashMap<String, Procedure>,
+    call_depth: usize,
+    /// The commander's location and locations of included files. The last one is the current.
+    locations: Vec<Location>,
+    /// Count of lines read from the commander.
+    read_lines_count: usize,
+    /// Arguments substituted for `$1`, `$<name>` and so on.
+    arguments: Arguments,
+    /// If it's set, the runner is finished by the first error.
+    strict: bool,
+    /// The class of error that finished the runner in strict mode.
+    failure: Option<ErrorClass>,
+    format: OutputFormat,
 }
 
 impl<C: Commander> Runner<C> {
     pub fn new(commander: C) -> Self {
+        Runner::with_arguments(commander, Arguments::default())
+    }
+
+    /// The `with_arguments` function creates the runner whose commands refer to given arguments.
+    pub fn with_arguments(commander: C, arguments: Arguments) -> Self {
         Runner {
             core: Core::new(),
+            locations: vec![Location::new(commander.source_name())],
             commander,
             completed: false,
+            procedures: HashMap::new(),
+            call_depth: 0,
+            read_lines_count: 0,
+            arguments,
+            strict: false,
+            failure: None,
+            format: OutputFormat::Text,
         }
     }
 
@@ -33,13 +150,352 @@ impl<C: Commander> Runner<C> {
         self.completed = true;
     }
 
+    /// The `set_strict` function turns strict mode on or off. In strict mode, the runner outputs
+    /// the first error with the file and the line of the failed command and finishes.
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// The `set_output_format` function sets how results of commands are written.
+    pub fn set_output_format(&mut self, format: OutputFormat) {
+        self.format = format;
+    }
+
+    /// The `failure` function returns class of error that finished the runner in strict mode.
+    pub fn failure(&self) -> Option<ErrorClass> {
+        self.failure
+    }
+
+    /// The `core` function returns the core commands are executed by.
+    pub fn core(&self) -> &Core {
+        &self.core
+    }
+
+    /// The `print` function writes the message of the runner itself. In JSON, the message without
+    /// the trailing new-line is written as the `text` field; an empty message isn't written at all.
     pub fn print(&mut self, msg: String) {
-        self.commander.write_result(msg);
+        match self.format {
+            OutputFormat::Text => self.commander.write_result(msg),
+            OutputFormat::Json if msg.is_empty() => {}
+            OutputFormat::Json => {
+                let text = Value::from(msg.trim_end_matches('\n'));
+                self.write_json("ok", "text", text)
+            }
+        }
+    }
+
+    /// The `procedure_templates` function returns templates of user-defined commands in
+    /// alphabetical order. For example, `write_entry <index> <lba>`.
+    pub fn procedure_templates(&self) -> Vec<String> {
+        let mut templates: Vec<String> = self
+            .procedures
+            .iter()
+            .map(|(name, procedure)| procedure.template(name))
+            .collect();
+        templates.sort();
+        templates
+    }
+
+    /// The `procedure_template` function returns template of the user-defined command with given
+    /// name.
+    pub fn procedure_template(&self, name: &str) -> Option<String> {
+        self.procedures
+            .get(name)
+            .map(|procedure| procedure.template(name))
+    }
+
+    /// The `output` function writes the result of command. Text is colored according to the
+    /// `color` option of the core.
+    pub fn output<T: ToOutput + ToJson>(&mut self, object: T) {
+        match self.format {
+            OutputFormat::Text => {
+                control::set_override(is_color_enabled(self.core.color_mode()));
+                let output = object.to_output();
+                control::unset_override();
+
+                self.commander.write_result(output)
+            }
+            OutputFormat::Json => self.write_json("ok", "output", object.to_json()),
+        }
+    }
+
+    /// The `write_json` function writes one line of JSON output: the object with the status, the
+    /// location of the command, the chain of includes and the field with given name and value.
+    fn write_json(&mut self, status: &str, field: &str, value: Value) {
+        let (inner, outer) = self.locations.split_last().unwrap();
+        let included_from: Vec<Value> = outer
+            .iter()
+            .rev()
+            .map(|location| json!({ "source": location.source, "line": location.line }))
+            .collect();
+
+        let mut object = json!({
+            "status": status,
+            "source": inner.source,
+            "line": inner.line,
+            "included_from": included_from,
+        });
+        object[field] = value;
+
+        self.commander.write_result(format!("{object}\n"));
+    }
+
+    /// The `output_error` function outputs the error. An error of a file starts with the file and
+    /// the line of the failed command, for example `script.fmod:14: Error: ...`; the chain of
+    /// includes follows it. In strict mode, the runner is finished then.
+    pub fn output_error<T: ErrorToOutput>(&mut self, error: T) {
+        let class = error.class();
+
+        match self.format {
+            OutputFormat::Text => self.write_error(error),
+            OutputFormat::Json => {
+                let error = json!({ "class": class.name(), "message": error.to_string() });
+                self.write_json("error", "error", error);
+            }
+        }
+
+        if self.strict {
+            self.failure = Some(class);
+            self.finish();
+        }
+    }
+
+    /// The `write_error` function writes the error as text with its location.
+    fn write_error<T: ErrorToOutput>(&mut self, error: T) {
+        let mut message = error.to_output();
+
+        if let [outer @ .., inner] = &self.locations[..] {
+            if inner.path.is_some() {
+                message.insert_str(0, &format!("{}:{}: ", inner.source, inner.line));
+            } else if self.strict {
+                message.push_str(&format!("    at {}:{}\n", inner.source, inner.line));
+            }
+
+            for location in outer.iter().rev() {
+                message.push_str(&format!(
+                    "    included from {}:{}\n",
+                    location.source, location.line
+                ));
+            }
+        }
+
+        self.commander.write_result(message);
+    }
+
+    /// The `include` function executes commands of the file at given path as if they were written
+    /// instead of the `include` command. A relative path is taken relative to the directory of
+    /// the current file.
+    ///
+    /// If the file is already being executed, the function returns error: including it again
+    /// would never end.
+    pub fn include(&mut self, path: &str) -> Result<(), StatementError> {
+        let path = self.resolve_path(path);
+        let source = path.display().to_string();
+
+        let could_not_include = |e: std::io::Error| StatementError::CouldNotInclude {
+            path: source.clone(),
+            message: e.to_string(),
+        };
+        let content = fs::read_to_string(&path).map_err(could_not_include)?;
+        let location = Location::new(source.clone());
+
+        if self
+            .locations
+            .iter()
+            .any(|other| other.path.is_some() && other.path == location.path)
+        {
+            return Err(StatementError::IncludeCycle { path: source });
+        }
+
+        let arguments = self.arguments.clone();
+        let mut lines = content
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, tokenize(line, &arguments)));
+
+        self.locations.push(location);
+        while let Some((line, tokens)) = lines.next() {
+            if self.completed {
+                break;
+            }
+            self.set_line(line);
+            self.execute_line(tokens, line, &mut || lines.next());
+        }
+        self.locations.pop();
+
+        Ok(())
+    }
+
+    /// The `resolve_path` function returns path of included file relative to the current file.
+    fn resolve_path(&self, path: &str) -> PathBuf {
+        let current = self.locations.last().unwrap();
+
+        // The source is a file only if its canonical path is known.
+        match current.path {
+            Some(_) => Path::new(&current.source)
+                .parent()
+                .map_or_else(|| PathBuf::from(path), |directory| directory.join(path)),
+            None => PathBuf::from(path),
+        }
+    }
+
+    fn set_line(&mut self, line: usize) {
+        self.locations.last_mut().unwrap().line = line;
+    }
+}
+
+impl<C: Commander> Runner<C> {
+    /// The `execute_statement` function executes the statement. Errors are written to output; a
+    /// failed command doesn't stop the rest of block.
+    fn execute_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Command { tokens, line } => {
+                self.set_line(*line);
+                self.execute_tokens(tokens)
+            }
+            Statement::For {
+                line,
+                variable,
+                start,
+                end,
+                body,
+            } => {
+                self.set_line(*line);
+                let bounds = self
+                    .core
+                    .evaluate_expression(start)
+                    .and_then(|start| Ok((start, self.core.evaluate_expression(end)?)));
+                let (start, end) = match bounds {
+                    Ok(bounds) => bounds,
+                    Err(e) => return self.output_error(e),
+                };
+
+                let shadowed = self.core.integer(variable);
+                for value in start..end {
+                    if let Err(e) = self.core.set_integer(variable, value) {
+                        self.output_error(e);
+                        break;
+                    }
+                    if !self.execute_body(body) {
+                        break;
+                    }
+                }
+                self.core.restore_integer(variable, shadowed);
+            }
+            Statement::If {
+                line,
+                condition,
+                then_body,
+                else_body,
+            } => {
+                self.set_line(*line);
+                let condition = match self.core.evaluate_expression(condition) {
+                    Ok(condition) => condition,
+                    Err(e) => return self.output_error(e),
+                };
+
+                self.execute_body(if condition != 0 { then_body } else { else_body });
+            }
+            Statement::Def { name, procedure } => {
+                self.procedures.insert(name.clone(), procedure.clone());
+                self.print(format!(
+                    "- Command {} is defined.\n",
+                    procedure.template(name)
+                ));
+            }
+        }
+    }
+
+    /// The `call_procedure` function executes user-defined command with given arguments.
+    fn call_procedure(&mut self, name: &str, arguments: &[Token]) -> Result<(), StatementError> {
+        if self.call_depth >= MAX_CALL_DEPTH {
+            return Err(StatementError::CallDepthExceeded {
+                name: name.to_string(),
+            });
+        }
+
+        let body = self.procedures[name].instantiate(name, arguments)?;
+
+        self.call_depth += 1;
+        self.execute_body(&body);
+        self.call_depth -= 1;
+
+        Ok(())
+    }
+
+    /// The `execute_body` function executes statements of block one by one. If the runner is
+    /// finished in the middle, the function returns `false`.
+    fn execute_body(&mut self, body: &[Statement]) -> bool {
+        for statement in body {
+            if self.completed {
+                return false;
+            }
+            self.execute_statement(statement);
+        }
+
+        !self.completed
     }
 
-    pub fn output<T: ToOutput>(&mut self, object: T) {
-        self.commander
-            .write_result(format!("{}", object.to_output()));
+    /// The `execute_line` function executes the statement starting with given line. If the
+    /// statement is a block, the rest of it is read with `next_line`.
+    fn execute_line(
+        &mut self,
+        tokens: Result<Vec<Token>, TokenizeError>,
+        line: usize,
+        next_line: &mut impl FnMut() -> Option<NumberedLine>,
+    ) {
+        let tokens = match tokens {
+            Ok(tokens) => tokens,
+            Err(e) => return self.output_error(e),
+        };
+
+        match read_statement(tokens, line, next_line) {
+            Ok(statement) => self.execute_statement(&statement),
+            Err(e) => self.output_error(e),
+        }
+    }
+
+    /// The `execute_tokens` function executes the command written by given tokens.
+    fn execute_tokens(&mut self, tokens: &[Token]) {
+        match parse_run_command(tokens) {
+            Some(Ok(run_command)) => {
+                if let Err(e) = execute_run_command(self, &run_command) {
+                    self.output_error(e);
+                };
+
+                return;
+            }
+            Some(Err(e)) => return self.output_error(e),
+            None => {}
+        }
+
+        let tokens = match self.core.evaluate(tokens) {
+            Ok(tokens) => tokens,
+            Err(e) => return self.output_error(e),
+        };
+
+        // User-defined commands are resolved before built-in ones.
+        if let Some(Token::Word(name)) = tokens.first() {
+            if self.procedures.contains_key(name) {
+                if let Err(e) = self.call_procedure(name, &tokens[1..]) {
+                    self.output_error(e);
+                }
+                return;
+            }
+        }
+
+        match parse_tokens(&tokens) {
+            Ok(command) => self.execute_command(command),
+            Err(e) => self.output_error(e),
+        }
+    }
+
+    /// The `execute_command` function executes the command of the core and outputs its result.
+    pub fn execute_command(&mut self, command: CoreCommand) {
+        match self.core.execute(command) {
+            Ok(core_output) => self.output(core_output),
+            Err(e) => self.output_error(e),
+        }
     }
 }
 
@@ -51,40 +507,44 @@ impl<C: Commander> Iterator for Runner<C> {
             return None;
         }
 
+        if C::is_terminal() {
+            self.commander.set_completion_names(
+                self.procedures.keys().cloned().collect(),
+                self.core.variable_names(),
+            );
+        }
+
         let input = self.commander.read_command()?;
+        self.read_lines_count += 1;
+        let line = self.read_lines_count;
+        self.set_line(line);
 
-        let tokens = match tokenize(&input) {
+        let tokens = match tokenize(&input, &self.arguments) {
             Ok(tokens) => tokens,
             Err(e) => {
-                self.output(e);
+                self.output_error(e);
                 return Some(());
             }
         };
 
-        if let Some(run_command) = parse_run_command(&tokens) {
-            if let Err(e) = execute_run_command(self, &run_command) {
-                self.output(e);
-            };
-
-            return Some(());
-        }
-
-        let command = match parse_tokens(&tokens) {
-            Ok(command) => command,
-            Err(e) => {
-                self.output(e);
-                return Some(());
-            }
+        let commander = &mut self.commander;
+        let read_lines_count = &mut self.read_lines_count;
+        let arguments = &self.arguments;
+        let mut next_line = || {
+            let text = commander.read_command()?;
+            *read_lines_count += 1;
+            Some((*read_lines_count, tokenize(&text, arguments)))
         };
-        let core_output = match self.core.execute(command) {
-            Ok(core_output) => core_output,
+
+        let statement = match read_statement(tokens, line, &mut next_line) {
+            Ok(statement) => statement,
             Err(e) => {
-                self.output(e);
+                self.output_error(e);
                 return Some(());
             }
         };
 
-        self.output(core_output);
+        self.execute_statement(&statement);
 
         Some(())
     }
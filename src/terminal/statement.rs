@@ -0,0 +1,473 @@
+use super::tokenizer::TokenizeError;
+
+use crate::core::Token;
+
+use std::collections::HashMap;
+
+pub mod error {
+    use crate::terminal::tokenizer::TokenizeError;
+
+    use std::error::Error as ErrorTrait;
+    use std::fmt::{Display, Formatter, Result as FmtResult};
+
+    #[derive(Debug)]
+    pub enum StatementError {
+        /// The file ended before the block was closed.
+        UnclosedBlock,
+        UnexpectedClosingBrace,
+        /// A header of block doesn't end with the opening brace.
+        MissingOpeningBrace,
+        InvalidFor,
+        InvalidIf,
+        InvalidDef,
+        /// Something is written after the closing brace.
+        InvalidClosing,
+        /// A line of block couldn't be tokenized.
+        Tokenize {
+            e: TokenizeError,
+        },
+        /// A user-defined command is called with wrong count of arguments.
+        WrongArgumentsCount {
+            name: String,
+            expected: usize,
+            got: usize,
+        },
+        CouldNotInclude {
+            path: String,
+            message: String,
+        },
+        /// The file is included while it's being executed.
+        IncludeCycle {
+            path: String,
+        },
+        /// User-defined commands call each other too deep. Probably, it's an endless recursion.
+        CallDepthExceeded {
+            name: String,
+        },
+    }
+
+    impl Display for StatementError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            write!(f, "StatementError: ")?;
+
+            match self {
+                StatementError::UnclosedBlock => write!(f, "UnclosedBlock"),
+                StatementError::UnexpectedClosingBrace => write!(f, "UnexpectedClosingBrace"),
+                StatementError::MissingOpeningBrace => write!(f, "MissingOpeningBrace"),
+                StatementError::InvalidFor => {
+                    write!(f, "InvalidFor: for <variable> in <start>..<end> {{")
+                }
+                StatementError::InvalidIf => write!(f, "InvalidIf: if <condition> {{"),
+                StatementError::InvalidDef => {
+                    write!(f, "InvalidDef: def <name>(<parameter>, ...) {{")
+                }
+                StatementError::InvalidClosing => {
+                    write!(
+                        f,
+                        "InvalidClosing: }}, }} else {{ or }} else if <condition> {{"
+                    )
+                }
+                StatementError::Tokenize { e } => write!(f, "{}", e),
+                StatementError::WrongArgumentsCount {
+                    name,
+                    expected,
+                    got,
+                } => write!(
+                    f,
+                    "WrongArgumentsCount: {} takes {} arguments, but {} were given",
+                    name, expected, got
+                ),
+                StatementError::CouldNotInclude { path, message } => {
+                    write!(f, "CouldNotInclude: {}: {}", path, message)
+                }
+                StatementError::IncludeCycle { path } => {
+                    write!(f, "IncludeCycle: {}", path)
+                }
+                StatementError::CallDepthExceeded { name } => {
+                    write!(f, "CallDepthExceeded: {}", name)
+                }
+            }
+        }
+    }
+
+    impl ErrorTrait for StatementError {}
+}
+
+pub use error::StatementError;
+
+const FOR_WORD: &str = "for";
+const IN_WORD: &str = "in";
+const IF_WORD: &str = "if";
+const DEF_WORD: &str = "def";
+const ELSE_WORD: &str = "else";
+
+const OPENING_BRACE: Token = Token::Punct("{");
+const CLOSING_BRACE: Token = Token::Punct("}");
+const RANGE: Token = Token::Punct("..");
+const OPENING_PARENTHESIS: Token = Token::Punct("(");
+const CLOSING_PARENTHESIS: Token = Token::Punct(")");
+const COMMA: Token = Token::Punct(",");
+
+/// Tokens of a line of script with its number. Lines are counted from one.
+pub type NumberedLine = (usize, Result<Vec<Token>, TokenizeError>);
+
+/// The `Statement` enumeration is a unit of execution. Blocks contain statements, so one statement
+/// can span multiple lines.
+#[derive(Clone)]
+pub enum Statement {
+    /// A line with a command. The tokens aren't evaluated yet.
+    Command { tokens: Vec<Token>, line: usize },
+    /// `for <variable> in <start>..<end> { ... }`. The bounds are evaluated once before the loop;
+    /// `<end>` isn't included.
+    For {
+        line: usize,
+        variable: String,
+        start: Vec<Token>,
+        end: Vec<Token>,
+        body: Vec<Statement>,
+    },
+    /// `if <condition> { ... } else { ... }`. `else if` is an `If` inside `else_body`.
+    If {
+        line: usize,
+        condition: Vec<Token>,
+        then_body: Vec<Statement>,
+        else_body: Vec<Statement>,
+    },
+    /// `def <name>(<parameter>, ...) { ... }` defines a command. See `Procedure`.
+    Def { name: String, procedure: Procedure },
+}
+
+impl Statement {
+    /// The `substitute` function returns the statement where words bound in `bindings` are
+    /// replaced by their tokens. Names of commands and bodies of nested definitions are left as
+    /// they are.
+    fn substitute(&self, bindings: &HashMap<&str, &Token>) -> Statement {
+        let substitute_tokens = |tokens: &[Token]| -> Vec<Token> {
+            tokens
+                .iter()
+                .map(|token| match token {
+                    Token::Word(word) => bindings.get(word.as_str()).copied().unwrap_or(token),
+                    _ => token,
+                })
+                .cloned()
+                .collect()
+        };
+
+        match self {
+            Statement::Command { tokens, line } => {
+                let tokens = match tokens.split_first() {
+                    Some((name, arguments)) => {
+                        let mut tokens = vec![name.clone()];
+                        tokens.extend(substitute_tokens(arguments));
+                        tokens
+                    }
+                    None => Vec::new(),
+                };
+                Statement::Command {
+                    tokens,
+                    line: *line,
+                }
+            }
+            Statement::For {
+                line,
+                variable,
+                start,
+                end,
+                body,
+            } => Statement::For {
+                line: *line,
+                variable: variable.clone(),
+                start: substitute_tokens(start),
+                end: substitute_tokens(end),
+                body: body.iter().map(|s| s.substitute(bindings)).collect(),
+            },
+            Statement::If {
+                line,
+                condition,
+                then_body,
+                else_body,
+            } => Statement::If {
+                line: *line,
+                condition: substitute_tokens(condition),
+                then_body: then_body.iter().map(|s| s.substitute(bindings)).collect(),
+                else_body: else_body.iter().map(|s| s.substitute(bindings)).collect(),
+            },
+            Statement::Def { .. } => self.clone(),
+        }
+    }
+}
+
+/// The `Procedure` structure is a user-defined command. When it's called, parameters in its body
+/// are replaced by the arguments, so an argument can be an integer or a name of buffer or file.
+#[derive(Clone)]
+pub struct Procedure {
+    parameters: Vec<String>,
+    body: Vec<Statement>,
+}
+
+impl Procedure {
+    pub fn parameters(&self) -> &[String] {
+        &self.parameters
+    }
+
+    /// The `instantiate` function returns the body where the parameters are replaced by given
+    /// arguments. If count of the arguments is wrong, the function returns error.
+    pub fn instantiate(
+        &self,
+        name: &str,
+        arguments: &[Token],
+    ) -> Result<Vec<Statement>, StatementError> {
+        if arguments.len() != self.parameters.len() {
+            return Err(StatementError::WrongArgumentsCount {
+                name: name.to_string(),
+                expected: self.parameters.len(),
+                got: arguments.len(),
+            });
+        }
+
+        let bindings: HashMap<&str, &Token> = self
+            .parameters
+            .iter()
+            .map(String::as_str)
+            .zip(arguments)
+            .collect();
+
+        Ok(self
+            .body
+            .iter()
+            .map(|statement| statement.substitute(&bindings))
+            .collect())
+    }
+
+    /// The `template` function returns the command as it should be called. For example,
+    /// `write_entry <index> <lba>`.
+    pub fn template(&self, name: &str) -> String {
+        let mut template = name.to_string();
+        for parameter in &self.parameters {
+            template.push_str(&format!(" <{parameter}>"));
+        }
+        template
+    }
+}
+
+fn is_word(token: Option<&Token>, word: &str) -> bool {
+    matches!(token, Some(Token::Word(w)) if w == word)
+}
+
+/// The `starts_block` function checks whether the tokens are a header of block.
+fn starts_block(tokens: &[Token]) -> bool {
+    [FOR_WORD, IF_WORD, DEF_WORD]
+        .into_iter()
+        .any(|word| is_word(tokens.first(), word))
+}
+
+/// The `read_body` function reads statements until the closing brace. The function returns the
+/// statements, the tokens of line with the closing brace and its number.
+fn read_body(
+    next_line: &mut impl FnMut() -> Option<NumberedLine>,
+) -> Result<(Vec<Statement>, Vec<Token>, usize), StatementError> {
+    let mut body = Vec::new();
+
+    loop {
+        let (line, tokens) = next_line().ok_or(StatementError::UnclosedBlock)?;
+        let tokens = tokens.map_err(|e| StatementError::Tokenize { e })?;
+
+        match tokens.first() {
+            Some(token) if *token == CLOSING_BRACE => return Ok((body, tokens, line)),
+            Some(_) => body.push(read_statement(tokens, line, next_line)?),
+            None => {}
+        }
+    }
+}
+
+/// The `parse_for_header` function returns the variable and the bounds of the `for` block. The
+/// header is given without the `for` word and the opening brace.
+fn parse_for_header(header: &[Token]) -> Option<(String, &[Token], &[Token])> {
+    let Some(Token::Word(variable)) = header.first() else {
+        return None;
+    };
+    if !is_word(header.get(1), IN_WORD) {
+        return None;
+    }
+
+    let range = &header[2..];
+    let range_index = range.iter().position(|token| *token == RANGE)?;
+    let (start, end) = (&range[..range_index], &range[range_index + 1..]);
+    if start.is_empty() || end.is_empty() {
+        return None;
+    }
+
+    Some((variable.clone(), start, end))
+}
+
+/// The `read_for` function reads the `for` block. The header is given without the `for` word and
+/// the opening brace.
+///
+/// The body is read even if the header is invalid, so the rest of block isn't taken for commands.
+fn read_for(
+    header: &[Token],
+    line: usize,
+    next_line: &mut impl FnMut() -> Option<NumberedLine>,
+) -> Result<Statement, StatementError> {
+    let parsed_header = parse_for_header(header);
+
+    let (body, closing, _) = read_body(next_line)?;
+    if closing.len() != 1 {
+        return Err(StatementError::InvalidClosing);
+    }
+
+    let (variable, start, end) = parsed_header.ok_or(StatementError::InvalidFor)?;
+
+    Ok(Statement::For {
+        line,
+        variable,
+        start: start.to_vec(),
+        end: end.to_vec(),
+        body,
+    })
+}
+
+/// The `read_if` function reads the `if` block with `else` branches. The header is given without
+/// the `if` word and the opening brace.
+///
+/// Like `read_for`, the function reads all branches even if the condition is empty.
+fn read_if(
+    condition: &[Token],
+    line: usize,
+    next_line: &mut impl FnMut() -> Option<NumberedLine>,
+) -> Result<Statement, StatementError> {
+    let (then_body, closing, closing_line) = read_body(next_line)?;
+
+    let else_body = match &closing[1..] {
+        [] => Vec::new(),
+        [Token::Word(else_word), brace] if else_word == ELSE_WORD && *brace == OPENING_BRACE => {
+            let (else_body, closing, _) = read_body(next_line)?;
+            if closing.len() != 1 {
+                return Err(StatementError::InvalidClosing);
+            }
+            else_body
+        }
+        [Token::Word(else_word), else_if_header @ ..] if else_word == ELSE_WORD => {
+            vec![read_statement(
+                else_if_header.to_vec(),
+                closing_line,
+                next_line,
+            )?]
+        }
+        _ => return Err(StatementError::InvalidClosing),
+    };
+
+    if condition.is_empty() {
+        return Err(StatementError::InvalidIf);
+    }
+
+    Ok(Statement::If {
+        line,
+        condition: condition.to_vec(),
+        then_body,
+        else_body,
+    })
+}
+
+/// The `parse_def_header` function returns the name and the parameters of the `def` block. The
+/// header is given without the `def` word and the opening brace. Parentheses can be omitted if
+/// there're no parameters.
+fn parse_def_header(header: &[Token]) -> Option<(String, Vec<String>)> {
+    let Some((Token::Word(name), rest)) = header.split_first() else {
+        return None;
+    };
+
+    if rest.is_empty() {
+        return Some((name.clone(), Vec::new()));
+    }
+
+    if rest.first() != Some(&OPENING_PARENTHESIS) || rest.last() != Some(&CLOSING_PARENTHESIS) {
+        return None;
+    }
+    let list = &rest[1..rest.len() - 1];
+
+    let mut parameters = Vec::new();
+    for (index, token) in list.iter().enumerate() {
+        match token {
+            Token::Word(parameter) if index % 2 == 0 && !parameters.contains(parameter) => {
+                parameters.push(parameter.clone())
+            }
+            token if index % 2 == 1 && *token == COMMA => {}
+            _ => return None,
+        }
+    }
+
+    if list.len() % 2 == 0 && !list.is_empty() {
+        return None;
+    }
+
+    Some((name.clone(), parameters))
+}
+
+/// The `read_def` function reads the `def` block. The header is given without the `def` word and
+/// the opening brace.
+///
+/// Like `read_for`, the function reads the body even if the header is invalid.
+fn read_def(
+    header: &[Token],
+    next_line: &mut impl FnMut() -> Option<NumberedLine>,
+) -> Result<Statement, StatementError> {
+    let parsed_header = parse_def_header(header);
+
+    let (body, closing, _) = read_body(next_line)?;
+    if closing.len() != 1 {
+        return Err(StatementError::InvalidClosing);
+    }
+
+    let (name, parameters) = parsed_header.ok_or(StatementError::InvalidDef)?;
+
+    Ok(Statement::Def {
+        name,
+        procedure: Procedure { parameters, body },
+    })
+}
+
+/// The `read_statement` function makes statement on the tokens of line with given number. If the
+/// line starts a block, the rest of the block is read with `next_line`.
+///
+/// #### Example
+/// ```ignore
+/// for i in 0..4 {
+///     buffer_set_byte mbr 446+i*16 0x80
+/// }
+/// if size(mbr) != 512 {
+///     make_buffer mbr 512
+/// } else {
+///     fill_buffer mbr 0 0 512
+/// }
+/// def write_entry(buffer, index, lba) {
+///     buffer_write_int buffer 454+index*16 u32 le lba
+/// }
+/// ```
+pub fn read_statement(
+    tokens: Vec<Token>,
+    line: usize,
+    next_line: &mut impl FnMut() -> Option<NumberedLine>,
+) -> Result<Statement, StatementError> {
+    if tokens.first() == Some(&CLOSING_BRACE) {
+        return Err(StatementError::UnexpectedClosingBrace);
+    }
+
+    if !starts_block(&tokens) {
+        return Ok(Statement::Command { tokens, line });
+    }
+
+    if tokens.last() != Some(&OPENING_BRACE) {
+        return Err(StatementError::MissingOpeningBrace);
+    }
+
+    let header = &tokens[1..tokens.len() - 1];
+
+    if is_word(tokens.first(), FOR_WORD) {
+        read_for(header, line, next_line)
+    } else if is_word(tokens.first(), IF_WORD) {
+        read_if(header, line, next_line)
+    } else {
+        read_def(header, next_line)
+    }
+}
@@ -55,7 +55,7 @@ impl Terminal {
     /// The `_read_line` function returns result of getting string from terminal. The function can
     /// be blocked.
     fn _read_line(&mut self) -> IoResult<String> {
-        self.out_stream.write(PROMPT.as_bytes())?;
+        self.out_stream.write_all(PROMPT.as_bytes())?;
         self.out_stream.flush()?;
 
         let mut buffer = String::new();
@@ -66,7 +66,7 @@ impl Terminal {
 
     /// The `_write` function returns result of writing string to terminal.
     fn _write(&mut self, string: &str) -> IoResult<()> {
-        self.out_stream.write(string.as_bytes())?;
+        self.out_stream.write_all(string.as_bytes())?;
         self.out_stream.flush()?;
 
         Ok(())
@@ -1,21 +1,52 @@
+use super::arguments::Arguments;
+
 use crate::core::token::Token;
 
+use std::borrow::Cow;
+use std::str::Chars;
+
 pub mod error {
     use std::error::Error as ErrorTrait;
     use std::fmt::{Display, Formatter, Result as FmtResult};
 
     #[derive(Debug)]
-    pub enum TokenizeError<'a> {
-        CouldNotTokenizeWord { word: &'a str },
+    pub enum TokenizeError {
+        CouldNotTokenizeWord { word: String },
+        /// The script refers to the argument it isn't launched with.
+        UndefinedArgument { name: String },
+        /// A string literal contains an unknown escape sequence.
+        InvalidEscape { sequence: String },
+        /// An integer literal doesn't fit the integer of the machine's width.
+        TooBigInteger { word: String },
     }
 
-    impl<'a> TokenizeError<'a> {
-        pub fn couldnot_tokenize_word(word: &'a str) -> Self {
-            TokenizeError::CouldNotTokenizeWord { word }
+    impl TokenizeError {
+        pub fn couldnot_tokenize_word(word: &str) -> Self {
+            TokenizeError::CouldNotTokenizeWord {
+                word: word.to_string(),
+            }
+        }
+
+        pub fn undefined_argument(name: &str) -> Self {
+            TokenizeError::UndefinedArgument {
+                name: name.to_string(),
+            }
+        }
+
+        pub fn invalid_escape(sequence: &str) -> Self {
+            TokenizeError::InvalidEscape {
+                sequence: sequence.to_string(),
+            }
+        }
+
+        pub fn too_big_integer(word: &str) -> Self {
+            TokenizeError::TooBigInteger {
+                word: word.to_string(),
+            }
         }
     }
 
-    impl<'a> Display for TokenizeError<'a> {
+    impl Display for TokenizeError {
         fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
             write!(f, "TokenizeError: ")?;
 
@@ -23,29 +54,77 @@ pub mod error {
                 TokenizeError::CouldNotTokenizeWord { word } => {
                     write!(f, "{}", word)
                 }
+                TokenizeError::UndefinedArgument { name } => {
+                    write!(f, "UndefinedArgument: ${}", name)
+                }
+                TokenizeError::InvalidEscape { sequence } => {
+                    write!(f, "InvalidEscape: {}", sequence)
+                }
+                TokenizeError::TooBigInteger { word } => {
+                    write!(f, "TooBigInteger: {}", word)
+                }
             }
         }
     }
 
-    impl<'a> ErrorTrait for TokenizeError<'a> {}
+    impl ErrorTrait for TokenizeError {}
 }
 
 pub use error::TokenizeError;
 
-/// The `tokenize_integer` returns `Token::UInt` taking a word.
+const STRING_QUOTE: char = '"';
+const ESCAPE_CHAR: char = '\\';
+const PATH_SEPARATOR: char = '/';
+/// Beginnings of words that are always paths.
+const PATH_PREFIXES: [&str; 4] = ["/", "./", "../", "~/"];
+const ARGUMENT_PREFIX: char = '$';
+const WILDCARD: &str = "??";
+/// Punctuation of expressions and blocks. Longer ones go first, so `<=` isn't taken for `<`.
+const PUNCTS: [&str; 21] = [
+    "==", "!=", "<=", ">=", "&&", "||", "..", "+", "-", "*", "/", "%", "(", ")", ",", "=", "<",
+    ">", "!", "{", "}",
+];
+const RANGE_PUNCT: &str = "..";
+
+/// The `tokenize_integer` returns `Token::UInt` taking a word. If the integer doesn't fit
+/// `usize`, the function returns `Err`.
 ///
 /// Accepted guarantees:
 /// - `is_integer(word)`.
 /// - `!word.is_empty()`
-fn tokenize_integer(word: &str) -> Token {
+fn tokenize_integer(word: &str) -> Result<Token, TokenizeError> {
     debug_assert!(is_integer(word));
     debug_assert!(!word.is_empty());
 
-    if word.get(0..2).map(|substr| substr == "0x").unwrap_or(false) {
-        Token::uinteger(usize::from_str_radix(&word[2..], 16).unwrap())
+    let value = if word.get(0..2).map(|substr| substr == "0x").unwrap_or(false) {
+        usize::from_str_radix(&word[2..], 16)
     } else {
-        Token::uinteger(usize::from_str_radix(word, 10).unwrap())
-    }
+        word.parse::<usize>()
+    };
+
+    value
+        .map(Token::uinteger)
+        .map_err(|_| TokenizeError::too_big_integer(word))
+}
+
+/// The `tokenize_negative_integer` returns `Token::SInt` taking a word. If the integer is too
+/// small, the function returns `Err`.
+///
+/// Accepted guarantees:
+/// - `is_negative_integer(word)`.
+fn tokenize_negative_integer(word: &str) -> Result<Token, TokenizeError> {
+    debug_assert!(is_negative_integer(word));
+
+    let Token::UInt(magnitude) = tokenize_integer(&word[1..])? else { unreachable!() };
+
+    0_isize
+        .checked_sub_unsigned(magnitude)
+        .map(Token::sinteger)
+        .ok_or_else(|| TokenizeError::too_big_integer(word))
+}
+
+fn is_negative_integer(word: &str) -> bool {
+    word.len() > 1 && word.starts_with('-') && is_integer(&word[1..])
 }
 
 fn is_integer(word: &str) -> bool {
@@ -54,9 +133,9 @@ fn is_integer(word: &str) -> bool {
             && word
                 .chars()
                 .skip(2)
-                .all(|ch| matches!(ch, '0'..='9' | 'a' ..= 'f' | 'A' ..= 'F'))
+                .all(|ch: char| ch.is_ascii_hexdigit())
     } else {
-        word.chars().all(|ch| matches!(ch, '0'..='9'))
+        word.chars().all(|ch: char| ch.is_ascii_digit())
     }
 }
 
@@ -72,11 +151,125 @@ fn tokenize_name(word: &str) -> Token {
     Token::word(word.to_string())
 }
 
+/// The `unescape_char` function returns the character an escape sequence stands for. The
+/// sequence is taken from `chars` following the backslash. `\xHH` may only be an ASCII code.
+fn unescape_char(chars: &mut Chars) -> Result<char, TokenizeError> {
+    let escaped = match chars.next() {
+        Some('n') => '\n',
+        Some('r') => '\r',
+        Some('t') => '\t',
+        Some('0') => '\0',
+        Some(ESCAPE_CHAR) => ESCAPE_CHAR,
+        Some(STRING_QUOTE) => STRING_QUOTE,
+        Some('x') => {
+            let digits: String = chars.by_ref().take(2).collect();
+            match u8::from_str_radix(&digits, 16) {
+                Ok(code) if digits.len() == 2 && code.is_ascii() => char::from(code),
+                Ok(_) if digits.len() == 2 => {
+                    return Err(TokenizeError::invalid_escape(&format!(
+                        "\\x{digits} isn't an ASCII code; write bytes above 0x7F as numbers"
+                    )))
+                }
+                _ => return Err(TokenizeError::invalid_escape(&format!("\\x{digits}"))),
+            }
+        }
+        Some(ch) => return Err(TokenizeError::invalid_escape(&format!("\\{ch}"))),
+        None => return Err(TokenizeError::invalid_escape("\\")),
+    };
+
+    Ok(escaped)
+}
+
+/// The `tokenize_string` returns `Token::Str` taking a word. Escape sequences `\n`, `\r`, `\t`,
+/// `\0`, `\\`, `\"` and `\xHH` are replaced by characters they stand for.
+///
+/// Accepted guarantees:
+/// - `is_string(word)`.
+fn tokenize_string(word: &str) -> Result<Token, TokenizeError> {
+    debug_assert!(is_string(word));
+
+    let mut string = String::new();
+    let mut chars = word[1..word.len() - 1].chars();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            ESCAPE_CHAR => string.push(unescape_char(&mut chars)?),
+            _ => string.push(ch),
+        }
+    }
+
+    Ok(Token::string(string))
+}
+
+/// The `closing_quote` function returns index of the double quote closing the string literal the
+/// given string starts. Escaped quotes don't close the literal.
+fn closing_quote(string: &str) -> Option<usize> {
+    let mut escaped = false;
+
+    for (index, ch) in string.char_indices().skip(1) {
+        match ch {
+            _ if escaped => escaped = false,
+            ESCAPE_CHAR => escaped = true,
+            STRING_QUOTE => return Some(index),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// The `is_string` function checks whether the word is a string literal: it starts with the
+/// double quote and ends with the only unescaped double quote inside it.
+fn is_string(word: &str) -> bool {
+    word.starts_with(STRING_QUOTE) && closing_quote(word) == Some(word.len() - 1)
+}
+
+/// The `is_path_char` function checks whether the character may be in a path written without
+/// quotes.
+fn is_path_char(ch: char) -> bool {
+    ch.is_alphanumeric() || matches!(ch, '_' | '.' | '-' | '~' | '@' | ':' | PATH_SEPARATOR)
+}
+
+/// The `is_path` function checks whether the word is a path written without quotes: it consists
+/// of letters, digits and characters `_`, `.`, `-`, `~`, `@`, `:`, `/` and either starts with `/`,
+/// `./`, `../` or `~/` or has `/` and a dot in its last part like `build/boot.bin`.
+///
+/// Other words with `/` are division: `/` itself, `n/k`, `lba/2` and `/2`. `..` inside a part of
+/// path is a range, so `0..n/k` is a part of expression too.
+fn is_path(word: &str) -> bool {
+    let last_part = word.rsplit(PATH_SEPARATOR).next().unwrap_or_default();
+    let has_prefix = PATH_PREFIXES.iter().any(|prefix| word.starts_with(prefix));
+
+    word != PATH_PREFIXES[0]
+        && word.chars().all(is_path_char)
+        && word
+            .split(PATH_SEPARATOR)
+            .all(|part| part == RANGE_PUNCT || !part.contains(RANGE_PUNCT))
+        && (has_prefix || (word.contains(PATH_SEPARATOR) && last_part.contains('.')))
+        && (last_part.is_empty() || !is_integer(last_part))
+}
+
+fn is_wildcard(word: &str) -> bool {
+    word == WILDCARD
+}
+
 fn is_name(word: &str) -> bool {
     let mut chars = word.chars();
 
     matches!(chars.next().unwrap(), 'A'..='Z' | 'a'..='z' | '_' | '.')
         && chars.all(|ch| matches!(ch, 'A'..='Z' | 'a'..='z' | '_' | '.' | '0'..='9'))
+        && !word.contains(RANGE_PUNCT)
+}
+
+/// The `find_punct` function returns punctuation the string starts with.
+fn find_punct(string: &str) -> Option<&'static str> {
+    PUNCTS
+        .into_iter()
+        .find(|punct| string.starts_with(punct))
+}
+
+fn contains_punct(word: &str) -> bool {
+    PUNCTS.iter().any(|punct| word.contains(punct))
 }
 
 /// The `tokenize_word` function takes word and returns appropriate token. If the function couldn't
@@ -84,11 +277,17 @@ fn is_name(word: &str) -> bool {
 fn tokenize_word(word: &str) -> Result<Token, TokenizeError> {
     debug_assert!(!word.is_empty());
     debug_assert!(word.find('\n').is_none());
-    debug_assert!(word.find('\t').is_none());
-    debug_assert!(word.find(' ').is_none());
 
     if is_integer(word) {
-        Ok(tokenize_integer(word))
+        tokenize_integer(word)
+    } else if is_negative_integer(word) {
+        tokenize_negative_integer(word)
+    } else if is_string(word) {
+        tokenize_string(word)
+    } else if is_wildcard(word) {
+        Ok(Token::wildcard())
+    } else if is_path(word) {
+        Ok(Token::word(word.to_string()))
     } else if is_name(word) {
         Ok(tokenize_name(word))
     } else {
@@ -96,26 +295,289 @@ fn tokenize_word(word: &str) -> Result<Token, TokenizeError> {
     }
 }
 
+/// The `tokenize_expression` function splits the word on punctuation and tokenizes the pieces
+/// between them. So `16*2` or `size(mbr)` don't need whitespaces between tokens.
+fn tokenize_expression(word: &str) -> Result<Vec<Token>, TokenizeError> {
+    let mut tokens = Vec::new();
+    let mut piece_start = 0;
+    let mut index = 0;
+
+    while index < word.len() {
+        let Some(punct) = find_punct(&word[index..]) else {
+            index += word[index..].chars().next().unwrap().len_utf8();
+            continue;
+        };
+
+        if piece_start < index {
+            tokens.push(tokenize_word(&word[piece_start..index])?);
+        }
+        tokens.push(Token::punct(punct));
+        index += punct.len();
+        piece_start = index;
+    }
+
+    if piece_start < word.len() {
+        tokens.push(tokenize_word(&word[piece_start..])?);
+    }
+
+    Ok(tokens)
+}
+
+/// The `tokenize_whole_word` function tokenizes the word as one token if it's possible and as
+/// expression otherwise.
+fn tokenize_whole_word(word: &str) -> Result<Vec<Token>, TokenizeError> {
+    match tokenize_word(word) {
+        Ok(token) => Ok(vec![token]),
+        Err(TokenizeError::CouldNotTokenizeWord { .. })
+            if contains_punct(word) && !word.contains(STRING_QUOTE) =>
+        {
+            tokenize_expression(word)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// The `split_on_words` function splits the string on words separated by whitespaces. A string
+/// literal is kept as one word even if there're whitespaces or escaped quotes inside it.
 fn split_on_words(string: &str) -> Vec<&str> {
-    string.split_whitespace().collect()
+    let mut words = Vec::new();
+    let mut word_start: Option<usize> = None;
+    let mut in_quotes = false;
+    let mut escaped = false;
+
+    for (index, ch) in string.char_indices() {
+        match ch {
+            _ if escaped => escaped = false,
+            ESCAPE_CHAR if in_quotes => escaped = true,
+            STRING_QUOTE => in_quotes = !in_quotes,
+            _ => {}
+        }
+
+        if ch.is_whitespace() && !in_quotes {
+            if let Some(start) = word_start.take() {
+                words.push(&string[start..index]);
+            }
+        } else if word_start.is_none() {
+            word_start = Some(index);
+        }
+    }
+
+    if let Some(start) = word_start {
+        words.push(&string[start..]);
+    }
+
+    words
 }
 
-/// The `tokenize` function takes a string and parses it to tokens. If the function couldn't parse
-/// the string, it returns `Err`.
+/// The `substitute_arguments` function replaces each `$<name>` in the string by value of the
+/// script's argument with the name. `$1`, `$2`, ... are positional arguments. Arguments are
+/// substituted inside string literals too; `$$` stands for `$` itself. A `$` that isn't followed
+/// by a name is left as it is.
+fn substitute_arguments<'a>(
+    string: &'a str,
+    arguments: &Arguments,
+) -> Result<Cow<'a, str>, TokenizeError> {
+    if !string.contains(ARGUMENT_PREFIX) {
+        return Ok(Cow::Borrowed(string));
+    }
+
+    let mut result = String::with_capacity(string.len());
+    let mut rest = string;
+
+    while let Some(index) = rest.find(ARGUMENT_PREFIX) {
+        result.push_str(&rest[..index]);
+        rest = &rest[index + ARGUMENT_PREFIX.len_utf8()..];
+
+        if let Some(after_escape) = rest.strip_prefix(ARGUMENT_PREFIX) {
+            result.push(ARGUMENT_PREFIX);
+            rest = after_escape;
+            continue;
+        }
+
+        // A positional argument's number ends at the first non-digit, so `$1st` is `$1` and `st`.
+        let name_length = if rest.starts_with(|ch: char| ch.is_ascii_digit()) {
+            rest.find(|ch: char| !ch.is_ascii_digit())
+        } else {
+            rest.find(|ch: char| !ch.is_ascii_alphanumeric() && ch != '_')
+        }
+        .unwrap_or(rest.len());
+
+        let name = &rest[..name_length];
+        if name.is_empty() {
+            result.push(ARGUMENT_PREFIX);
+        } else {
+            let value = arguments
+                .get(name)
+                .ok_or_else(|| TokenizeError::undefined_argument(name))?;
+            result.push_str(value);
+        }
+
+        rest = &rest[name_length..];
+    }
+    result.push_str(rest);
+
+    Ok(Cow::Owned(result))
+}
+
+/// The `tokenize` function takes a string and parses it to tokens. Before that, references to
+/// the script's arguments are substituted (see `substitute_arguments`). If the function couldn't
+/// parse the string, it returns `Err`.
 ///
 /// #### Example
 /// ```ignore
 /// use terminal::tokenizer::tokenize;
 /// use core::token::Token;
 ///
-/// let tokens = tokenize("make_buffer mbr 512").unwrap();
+/// let arguments = Arguments::new(vec!["512".to_string()], HashMap::new());
+/// let tokens = tokenize("make_buffer mbr $1", &arguments).unwrap();
 /// assert_eq!(tokens, vec![
 ///     Token::word("make_buffer".to_string()),
 ///     Token::word("mbr".to_string()),
 ///     Token::uinteger(512),
 /// ]);
 /// ```
-pub fn tokenize(string: &str) -> Result<Vec<Token>, TokenizeError> {
-    let words = split_on_words(string);
-    words.into_iter().map(|word| tokenize_word(word)).collect()
+pub fn tokenize(string: &str, arguments: &Arguments) -> Result<Vec<Token>, TokenizeError> {
+    let string = substitute_arguments(string, arguments)?;
+    let mut tokens = Vec::new();
+
+    for word in split_on_words(&string) {
+        tokens.extend(tokenize_whole_word(word)?);
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashMap;
+
+    fn arguments() -> Arguments {
+        let named = HashMap::from([("name".to_string(), "mbr".to_string())]);
+        Arguments::new(vec!["512".to_string()], named)
+    }
+
+    fn tokens(string: &str) -> Vec<Token> {
+        tokenize(string, &arguments()).unwrap()
+    }
+
+    #[test]
+    fn arguments_are_substituted() {
+        assert_eq!(
+            tokens("make_buffer $name $1"),
+            vec![
+                Token::word("make_buffer".to_string()),
+                Token::word("mbr".to_string()),
+                Token::uinteger(512),
+            ]
+        );
+        assert_eq!(
+            tokens("\"$name is $1 bytes\""),
+            vec![Token::string("mbr is 512 bytes".to_string())]
+        );
+    }
+
+    #[test]
+    fn double_prefix_is_literal() {
+        assert_eq!(
+            tokens("\"$$5 off\""),
+            vec![Token::string("$5 off".to_string())]
+        );
+        assert_eq!(
+            tokens("\"$$name costs $$$1\""),
+            vec![Token::string("$name costs $512".to_string())]
+        );
+        assert_eq!(tokens("\"$ 5\""), vec![Token::string("$ 5".to_string())]);
+    }
+
+    #[test]
+    fn words_with_slashes_are_paths() {
+        for path in [
+            "/tmp/disk.img",
+            "build/boot.bin",
+            "./1.bin",
+            "../2023/image.bin",
+            "./boot-img.bin",
+            "~/images/disk.img",
+            "./dir/",
+        ] {
+            assert_eq!(tokens(path), vec![Token::word(path.to_string())], "{path}");
+        }
+    }
+
+    #[test]
+    fn division_is_not_path() {
+        let division = |left: Token, right: Token| vec![left, Token::punct("/"), right];
+
+        assert_eq!(
+            tokens("lba/2"),
+            division(Token::word("lba".to_string()), Token::uinteger(2))
+        );
+        assert_eq!(
+            tokens("size(b)/2")[3..],
+            division(Token::punct(")"), Token::uinteger(2))[..]
+        );
+        let word = |name: &str| Token::word(name.to_string());
+
+        assert_eq!(tokens("a / b"), division(word("a"), word("b")));
+        assert_eq!(tokens("n/k"), division(word("n"), word("k")));
+        assert_eq!(
+            tokens("10 / 2"),
+            division(Token::uinteger(10), Token::uinteger(2))
+        );
+        assert_eq!(
+            tokens("0..n/k"),
+            vec![
+                Token::uinteger(0),
+                Token::punct(".."),
+                Token::word("n".to_string()),
+                Token::punct("/"),
+                Token::word("k".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn too_big_integer_is_error() {
+        for word in [
+            "99999999999999999999999",
+            "0x10000000000000000",
+            "-99999999999999999999",
+        ] {
+            assert!(
+                matches!(
+                    tokenize(word, &arguments()),
+                    Err(TokenizeError::TooBigInteger { .. })
+                ),
+                "{word}"
+            );
+        }
+        assert_eq!(tokens("0xFFFF"), vec![Token::uinteger(0xFFFF)]);
+    }
+
+    #[test]
+    fn hex_escape_is_ascii_only() {
+        assert_eq!(
+            tokens("\"\\x41\\x7F\""),
+            vec![Token::string("A\x7F".to_string())]
+        );
+        for word in ["\"\\x80\"", "\"\\xFF\"", "\"\\x4\"", "\"\\xZZ\""] {
+            assert!(
+                matches!(
+                    tokenize(word, &arguments()),
+                    Err(TokenizeError::InvalidEscape { .. })
+                ),
+                "{word}"
+            );
+        }
+    }
+
+    #[test]
+    fn undefined_argument_is_error() {
+        assert!(matches!(
+            tokenize("make_buffer b $2", &arguments()),
+            Err(TokenizeError::UndefinedArgument { name }) if name == "2"
+        ));
+    }
 }